@@ -0,0 +1,173 @@
+// simulated backend => keeps a small in-memory user list and answers after a delay
+use std::fmt;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use std::future::Future;
+
+use iced::futures::future::{self, Either};
+use iced::futures::stream::{self, Stream};
+
+use crate::timer;
+
+// accounts known to the fake server
+static USERS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+// a demo account that always exists
+const DEMO_USER: (&str, &str) = ("demo@example.com", "password123");
+
+// emails on this domain get a malformed answer => exercises the error page
+const BROKEN_DOMAIN: &str = "@fail.test";
+
+// emails on this domain answer very slowly => exercises the request timeout
+const SLOW_DOMAIN: &str = "@slow.test";
+
+// how long the fake server takes to answer
+const LATENCY: Duration = Duration::from_millis(800);
+const SLOW_LATENCY: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiError {
+    InvalidCredentials,
+    EmailTaken,
+    Timeout,
+    Server(String),
+}
+
+impl ApiError {
+    // errors the forms know how to show => anything else goes to the error page
+    pub fn is_expected(&self) -> bool {
+        matches!(self, ApiError::InvalidCredentials | ApiError::EmailTaken)
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::InvalidCredentials => write!(f, "Incorrect email or password."),
+            ApiError::EmailTaken => write!(f, "An account with this email already exists."),
+            ApiError::Timeout => write!(f, "Server took too long."),
+            ApiError::Server(message) => write!(f, "Server error: {message}"),
+        }
+    }
+}
+
+// give up on a request that takes longer than `timeout`
+async fn with_timeout<T>(
+    timeout: Duration,
+    request: impl Future<Output = Result<T, ApiError>>,
+) -> Result<T, ApiError> {
+    match future::select(Box::pin(request), Box::pin(timer::sleep(timeout))).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => Err(ApiError::Timeout),
+    }
+}
+
+// the fake server's answer delay => slow for the slow domain
+async fn wait_for_server(email: &str, latency: Duration, timeout: Duration) -> Result<(), ApiError> {
+    let latency = if email.ends_with(SLOW_DOMAIN) { SLOW_LATENCY } else { latency };
+    with_timeout(timeout, async move {
+        timer::sleep(latency).await;
+        Ok(())
+    })
+    .await
+}
+
+// log in => resolves to the email of the signed in account
+pub async fn login(email: String, password: String, timeout: Duration) -> Result<String, ApiError> {
+    wait_for_server(&email, LATENCY, timeout).await?;
+    check_response(&email)?;
+
+    let users = USERS.lock().map_err(|_| ApiError::Server(String::from("user store poisoned")))?;
+    let known = (email.as_str(), password.as_str()) == DEMO_USER
+        || users.iter().any(|(e, p)| *e == email && *p == password);
+
+    if known {
+        Ok(email)
+    } else {
+        Err(ApiError::InvalidCredentials)
+    }
+}
+
+// steps the fake server walks through when creating an account
+const REGISTER_STEPS: usize = 3;
+
+// what each step does => labels for the stepper
+pub const REGISTER_STEP_LABELS: [&str; REGISTER_STEPS] = ["Checking details", "Creating account", "Finishing up"];
+
+// updates sent while an account is being created
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegisterEvent {
+    Progress(usize, usize), // steps done out of the total
+    Finished(Result<String, ApiError>),
+}
+
+// create an account => reports progress after each step, then the result
+// => each step has the full timeout
+pub fn register(email: String, password: String, timeout: Duration) -> impl Stream<Item = RegisterEvent> {
+    stream::unfold(Some(0), move |step| {
+        let (email, password) = (email.clone(), password.clone());
+        async move {
+            let step = step?;
+            if let Err(error) = wait_for_server(&email, LATENCY / 2, timeout).await {
+                return Some((RegisterEvent::Finished(Err(error)), None));
+            }
+
+            if step + 1 < REGISTER_STEPS {
+                return Some((RegisterEvent::Progress(step + 1, REGISTER_STEPS), Some(step + 1)));
+            }
+            Some((RegisterEvent::Finished(create_account(email, password)), None))
+        }
+    })
+}
+
+fn create_account(email: String, password: String) -> Result<String, ApiError> {
+    check_response(&email)?;
+    let mut users = USERS.lock().map_err(|_| ApiError::Server(String::from("user store poisoned")))?;
+    if email == DEMO_USER.0 || users.iter().any(|(e, _)| *e == email) {
+        return Err(ApiError::EmailTaken);
+    }
+    users.push((email.clone(), password));
+    Ok(email)
+}
+
+// outcome of an email availability check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckResult {
+    Available,
+    Taken,
+}
+
+// ask the server whether an email can still be registered
+pub async fn check_email(email: String, timeout: Duration) -> Result<CheckResult, ApiError> {
+    wait_for_server(&email, LATENCY / 2, timeout).await?;
+    check_response(&email)?;
+
+    let users = USERS.lock().map_err(|_| ApiError::Server(String::from("user store poisoned")))?;
+    if email == DEMO_USER.0 || users.iter().any(|(e, _)| *e == email) {
+        Ok(CheckResult::Taken)
+    } else {
+        Ok(CheckResult::Available)
+    }
+}
+
+// the fake server answers garbage for the broken domain
+fn check_response(email: &str) -> Result<(), ApiError> {
+    if email.ends_with(BROKEN_DOMAIN) {
+        Err(ApiError::Server(String::from("malformed response")))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeout_reads_as_a_slow_server() {
+        assert_eq!(ApiError::Timeout.to_string(), "Server took too long.");
+        // not a form error => the error page offers a retry
+        assert!(!ApiError::Timeout.is_expected());
+    }
+}
@@ -0,0 +1,95 @@
+// small keyed cache with a size cap and a time to live
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+pub struct TtlCache<V> {
+    entries: HashMap<String, (V, Instant)>, // value and when it was stored
+    capacity: usize,
+    ttl: Duration,
+}
+
+impl<V: Clone> TtlCache<V> {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity,
+            ttl,
+        }
+    }
+
+    // cached value for the exact key => expired entries count as a miss and are dropped
+    pub fn get(&mut self, key: &str, now: Instant) -> Option<V> {
+        let (value, stored_at) = self.entries.get(key)?;
+        if now.saturating_duration_since(*stored_at) < self.ttl {
+            return Some(value.clone());
+        }
+        self.entries.remove(key);
+        None
+    }
+
+    // store a value => the oldest entry is evicted once the cap is reached
+    pub fn insert(&mut self, key: String, value: V, now: Instant) {
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, stored_at))| *stored_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(key, (value, now));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn stored_values_are_hits_for_their_exact_key() {
+        let now = Instant::now();
+        let mut cache = TtlCache::new(4, TTL);
+        cache.insert(String::from("a@x.com"), 1, now);
+        assert_eq!(cache.get("a@x.com", now), Some(1));
+        assert_eq!(cache.get("A@x.com", now), None);
+        assert_eq!(cache.get("b@x.com", now), None);
+    }
+
+    #[test]
+    fn entries_expire_after_the_ttl() {
+        let now = Instant::now();
+        let mut cache = TtlCache::new(4, TTL);
+        cache.insert(String::from("a"), 1, now);
+        assert_eq!(cache.get("a", now + TTL - Duration::from_millis(1)), Some(1));
+        assert_eq!(cache.get("a", now + TTL), None);
+        // dropped on the miss => still gone when asked again
+        assert_eq!(cache.get("a", now), None);
+    }
+
+    #[test]
+    fn the_oldest_entry_makes_room() {
+        let now = Instant::now();
+        let mut cache = TtlCache::new(2, TTL);
+        cache.insert(String::from("a"), 1, now);
+        cache.insert(String::from("b"), 2, now + Duration::from_secs(1));
+        cache.insert(String::from("c"), 3, now + Duration::from_secs(2));
+        assert_eq!(cache.get("a", now), None);
+        assert_eq!(cache.get("b", now), Some(2));
+        assert_eq!(cache.get("c", now), Some(3));
+    }
+
+    #[test]
+    fn updating_a_key_evicts_nothing() {
+        let now = Instant::now();
+        let mut cache = TtlCache::new(2, TTL);
+        cache.insert(String::from("a"), 1, now);
+        cache.insert(String::from("b"), 2, now);
+        cache.insert(String::from("a"), 10, now);
+        assert_eq!(cache.get("a", now), Some(10));
+        assert_eq!(cache.get("b", now), Some(2));
+    }
+}
@@ -0,0 +1,30 @@
+// command line flags => `--route <page>` or a `rustui://<page>` link, `--demo`
+#[derive(Debug, Clone, Default)]
+pub struct Flags {
+    pub route: Option<String>, // page requested on startup, not yet validated
+    pub demo: bool,            // replay the scripted demo until real input arrives
+}
+
+impl Flags {
+    pub fn from_args() -> Self {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    // unknown arguments are ignored
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Self {
+        let mut flags = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--demo" {
+                flags.demo = true;
+            } else if arg == "--route" {
+                flags.route = args.next();
+            } else if let Some(route) = arg.strip_prefix("--route=") {
+                flags.route = Some(route.to_string());
+            } else if let Some(link) = arg.strip_prefix("rustui://") {
+                flags.route = Some(link.trim_end_matches('/').to_string());
+            }
+        }
+        flags
+    }
+}
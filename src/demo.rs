@@ -0,0 +1,50 @@
+// demo mode => replays a scripted session on a timer, e.g. for recording the tutorial GIF
+use std::time::Duration;
+
+use crate::Message;
+
+// time between two scripted steps
+pub const STEP_INTERVAL: Duration = Duration::from_millis(450);
+
+const DEMO_EMAIL: &str = "demo@example.com";
+const DEMO_PASSWORD: &str = "password123";
+
+// one looping run through the app => `None` steps just wait a beat
+pub fn script() -> Vec<Option<Message>> {
+    let mut steps = vec![
+        Some(Message::Router("Login".to_string())),
+        Some(Message::EmailChanged(String::new())),
+        Some(Message::PasswordChanged(String::new())),
+    ];
+    steps.extend(typing(DEMO_EMAIL, Message::EmailChanged));
+    steps.extend(typing(DEMO_PASSWORD, Message::PasswordChanged));
+    steps.extend([
+        Some(Message::ToggleTheme),
+        None,
+        Some(Message::ToggleTheme),
+        Some(Message::LoginSubmit),
+        None,
+        None,
+        None,
+        Some(Message::Logout),
+        Some(Message::Router("Register".to_string())),
+        None,
+        Some(Message::Router("Settings".to_string())),
+        None,
+        Some(Message::Router("Login".to_string())),
+        None,
+    ]);
+    steps
+}
+
+// type a value a few characters at a time
+fn typing(value: &str, message: fn(String) -> Message) -> Vec<Option<Message>> {
+    let chars: Vec<char> = value.chars().collect();
+    chars
+        .chunks(4)
+        .scan(String::new(), |typed, chunk| {
+            typed.extend(chunk);
+            Some(Some(message(typed.clone())))
+        })
+        .collect()
+}
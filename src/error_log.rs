@@ -0,0 +1,128 @@
+// recent errors kept for debugging => a bounded ring buffer, oldest entries drop off
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// where an error came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorSource {
+    Validation, // a form field failed validation
+    Request,    // the backend answered with an error
+    Storage,    // settings or presets could not be written
+}
+
+impl ErrorSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            ErrorSource::Validation => "validation",
+            ErrorSource::Request => "request",
+            ErrorSource::Storage => "storage",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ErrorEntry {
+    pub at: SystemTime,
+    pub source: ErrorSource,
+    pub message: String,
+}
+
+#[derive(Debug)]
+pub struct ErrorLog {
+    entries: VecDeque<ErrorEntry>,
+    capacity: usize,
+}
+
+impl ErrorLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    // record an error now
+    pub fn push(&mut self, source: ErrorSource, message: impl Into<String>) {
+        self.push_entry(ErrorEntry {
+            at: SystemTime::now(),
+            source,
+            message: message.into(),
+        });
+    }
+
+    // full => the oldest entry makes room
+    pub fn push_entry(&mut self, entry: ErrorEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    // oldest first
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &ErrorEntry> {
+        self.entries.iter()
+    }
+
+    pub fn latest(&self) -> Option<&ErrorEntry> {
+        self.entries.back()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+// wall clock time of day => `HH:MM:SS UTC`, no timezone database needed
+pub fn clock_time(at: SystemTime) -> String {
+    let seconds = at.duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs()) % 86_400;
+    format!(
+        "{:02}:{:02}:{:02} UTC",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(log: &ErrorLog) -> Vec<&str> {
+        log.entries().map(|entry| entry.message.as_str()).collect()
+    }
+
+    #[test]
+    fn entries_come_back_oldest_first() {
+        let mut log = ErrorLog::new(3);
+        assert!(log.is_empty());
+        log.push(ErrorSource::Validation, "first");
+        log.push(ErrorSource::Request, "second");
+        assert_eq!(messages(&log), ["first", "second"]);
+        assert_eq!(log.latest().map(|entry| entry.source), Some(ErrorSource::Request));
+    }
+
+    #[test]
+    fn a_full_log_drops_the_oldest_entry() {
+        let mut log = ErrorLog::new(2);
+        for message in ["one", "two", "three"] {
+            log.push(ErrorSource::Storage, message);
+        }
+        assert_eq!(messages(&log), ["two", "three"]);
+    }
+
+    #[test]
+    fn zero_capacity_keeps_nothing() {
+        let mut log = ErrorLog::new(0);
+        log.push(ErrorSource::Storage, "ignored");
+        assert!(log.is_empty());
+    }
+
+    #[test]
+    fn clock_time_is_utc_time_of_day() {
+        let at = UNIX_EPOCH + std::time::Duration::from_secs(2 * 86_400 + 13 * 3600 + 5 * 60 + 9);
+        assert_eq!(clock_time(at), "13:05:09 UTC");
+    }
+}
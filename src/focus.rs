@@ -0,0 +1,72 @@
+// keyboard focus helpers
+use iced::keyboard::{self, key::Named, Key};
+use iced::{mouse, Event};
+
+// how the user last interacted => focus rings only show for keyboard navigation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Modality {
+    Mouse,
+    Keyboard,
+}
+
+// keys that move focus or act on the focused widget
+fn is_navigation_key(key: &Key) -> bool {
+    matches!(
+        key,
+        Key::Named(
+            Named::Tab
+                | Named::Enter
+                | Named::ArrowUp
+                | Named::ArrowDown
+                | Named::ArrowLeft
+                | Named::ArrowRight
+        )
+    )
+}
+
+// the modality an event switches to, if it changes anything
+// => clicks mean mouse, navigation keys mean keyboard, typing keeps the current one
+pub fn modality_change(event: &Event) -> Option<Modality> {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(_)) => Some(Modality::Mouse),
+        Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if is_navigation_key(key) => {
+            Some(Modality::Keyboard)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_press(key: Key) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key,
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: None,
+        })
+    }
+
+    #[test]
+    fn clicks_switch_to_mouse() {
+        let click = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        assert_eq!(modality_change(&click), Some(Modality::Mouse));
+    }
+
+    #[test]
+    fn navigation_keys_switch_to_keyboard() {
+        for named in [Named::Tab, Named::Enter, Named::ArrowUp, Named::ArrowLeft] {
+            assert_eq!(modality_change(&key_press(Key::Named(named))), Some(Modality::Keyboard));
+        }
+    }
+
+    #[test]
+    fn typing_and_moving_the_mouse_change_nothing() {
+        assert_eq!(modality_change(&key_press(Key::Character("a".into()))), None);
+        assert_eq!(modality_change(&key_press(Key::Named(Named::Shift))), None);
+        let moved = Event::Mouse(mouse::Event::CursorMoved { position: iced::Point::ORIGIN });
+        assert_eq!(modality_change(&moved), None);
+    }
+}
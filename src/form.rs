@@ -0,0 +1,568 @@
+// form fields shared by the login and register pages
+use iced::widget::text_input;
+
+// every kind of input field a form can render
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldKind {
+    Username,
+    Email,
+    Password,
+    ConfirmPassword,
+    Phone,
+}
+
+impl FieldKind {
+    // every field in its default order
+    pub const ALL: [FieldKind; 5] = [
+        FieldKind::Username,
+        FieldKind::Email,
+        FieldKind::Password,
+        FieldKind::ConfirmPassword,
+        FieldKind::Phone,
+    ];
+
+    // key used when persisting the field order and as the widget id
+    pub fn key(self) -> &'static str {
+        match self {
+            FieldKind::Username => "username",
+            FieldKind::Email => "email",
+            FieldKind::Password => "password",
+            FieldKind::ConfirmPassword => "confirm_password",
+            FieldKind::Phone => "phone",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<FieldKind> {
+        FieldKind::ALL.into_iter().find(|kind| kind.key() == key.trim())
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FieldKind::Username => "Username",
+            FieldKind::Email => "Email Address",
+            FieldKind::Password => "Password",
+            FieldKind::ConfirmPassword => "Confirm Password",
+            FieldKind::Phone => "Phone Number",
+        }
+    }
+
+    pub fn placeholder(self) -> &'static str {
+        match self {
+            FieldKind::Username => "Username... ",
+            FieldKind::Email => "Email Address... ",
+            FieldKind::Password => "Password... ",
+            FieldKind::ConfirmPassword => "Confirm Password... ",
+            FieldKind::Phone => "Phone (optional)... ",
+        }
+    }
+
+    // optional fields may stay empty => they don't hold back the submit button
+    pub fn is_optional(self) -> bool {
+        self == FieldKind::Phone
+    }
+
+    // secret fields are masked and never stored
+    pub fn is_secret(self) -> bool {
+        matches!(self, FieldKind::Password | FieldKind::ConfirmPassword)
+    }
+
+    // id of the text input rendering this field => used to move focus
+    pub fn input_id(self) -> text_input::Id {
+        text_input::Id::new(self.key())
+    }
+}
+
+// longest value any text input accepts, in characters
+pub const MAX_INPUT_CHARS: usize = 4096;
+
+// cut a value down to the input limit => true when something was dropped
+pub fn cap_length(mut value: String) -> (String, bool) {
+    match value.char_indices().nth(MAX_INPUT_CHARS) {
+        Some((index, _)) => {
+            value.truncate(index);
+            (value, true)
+        }
+        None => (value, false),
+    }
+}
+
+// tab characters => dropped, Tab moves focus and a single line field never holds one
+// typed tabs never reach the input, but a pasted value can carry them
+pub fn strip_tabs(value: String) -> String {
+    if value.contains('\t') {
+        value.replace('\t', "")
+    } else {
+        value
+    }
+}
+
+// keeps only the fields a form renders, in the configured order
+pub fn ordered_fields(order: &[FieldKind], form: &[FieldKind]) -> Vec<FieldKind> {
+    order.iter().copied().filter(|kind| form.contains(kind)).collect()
+}
+
+// the field after `current` in the rendered order, if any
+pub fn next_field(fields: &[FieldKind], current: FieldKind) -> Option<FieldKind> {
+    let index = fields.iter().position(|kind| *kind == current)?;
+    fields.get(index + 1).copied()
+}
+
+// the field `offset` steps away from `current`, wrapping around at both ends
+// => with nothing focused yet, forward starts at the first field and backward at the last
+pub fn cycle_field(fields: &[FieldKind], current: Option<FieldKind>, offset: isize) -> Option<FieldKind> {
+    let len = fields.len() as isize;
+    if len == 0 {
+        return None;
+    }
+    let index = match current.and_then(|current| fields.iter().position(|kind| *kind == current)) {
+        Some(index) => (index as isize + offset).rem_euclid(len),
+        None if offset >= 0 => 0,
+        None => len - 1,
+    };
+    fields.get(index as usize).copied()
+}
+
+// the field that lost focus when focus moves from `previous` to `next`, if any
+pub fn blurred_field(previous: Option<FieldKind>, next: Option<FieldKind>) -> Option<FieldKind> {
+    match previous {
+        Some(previous) if Some(previous) != next => Some(previous),
+        _ => None,
+    }
+}
+
+// makes sure a persisted order holds every field exactly once
+pub fn normalize_order(order: Vec<FieldKind>) -> Vec<FieldKind> {
+    let mut normalized: Vec<FieldKind> = Vec::new();
+    for kind in order.into_iter().chain(FieldKind::ALL) {
+        if !normalized.contains(&kind) {
+            normalized.push(kind);
+        }
+    }
+    normalized
+}
+
+// validate a single field value
+pub fn validate(kind: FieldKind, value: &str) -> Result<(), String> {
+    match kind {
+        FieldKind::Username => {
+            if value.trim().is_empty() {
+                Err(String::from("Username is required."))
+            } else if value.trim().chars().count() < 3 {
+                Err(String::from("Username must be at least 3 characters."))
+            } else {
+                Ok(())
+            }
+        }
+        FieldKind::Email => {
+            if value.trim().is_empty() {
+                Err(String::from("Email is required."))
+            } else if !is_valid_email(value.trim()) {
+                Err(String::from("Enter a valid email address."))
+            } else {
+                Ok(())
+            }
+        }
+        FieldKind::Password => {
+            if value.is_empty() {
+                Err(String::from("Password is required."))
+            } else if value.chars().count() < 8 {
+                Err(String::from("Password must be at least 8 characters."))
+            } else {
+                Ok(())
+            }
+        }
+        FieldKind::ConfirmPassword => {
+            if value.is_empty() {
+                Err(String::from(CONFIRM_REQUIRED))
+            } else {
+                Ok(())
+            }
+        }
+        // holds the raw digits => the mask is only for display
+        FieldKind::Phone => {
+            if !value.is_empty() && (value.len() != PHONE_DIGITS || !value.chars().all(|c| c.is_ascii_digit())) {
+                Err(format!("Enter all {PHONE_DIGITS} digits of the phone number."))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+// error of a confirmation left empty
+pub const CONFIRM_REQUIRED: &str = "Please confirm your password.";
+
+// validate a field against the rest of the form => the confirmation must repeat the password
+pub fn validate_in_form<'a>(kind: FieldKind, value: impl Fn(FieldKind) -> &'a str) -> Result<(), String> {
+    validate(kind, value(kind))?;
+    if kind == FieldKind::ConfirmPassword && value(kind) != value(FieldKind::Password) {
+        return Err(String::from("Passwords do not match."));
+    }
+    Ok(())
+}
+
+// validate the given fields in order => errors come back in the same order
+pub fn validate_fields<'a>(
+    fields: &[FieldKind],
+    value: impl Fn(FieldKind) -> &'a str,
+) -> Vec<(FieldKind, String)> {
+    fields
+        .iter()
+        .filter_map(|kind| validate_in_form(*kind, &value).err().map(|error| (*kind, error)))
+        .collect()
+}
+
+// digits in a phone number => (123) 456-7890
+pub const PHONE_DIGITS: usize = 10;
+
+// the digits of typed or pasted text => a full number with a leading country code 1 drops the 1
+pub fn phone_digits(text: &str) -> String {
+    let mut digits: String = text.chars().filter(char::is_ascii_digit).collect();
+    if digits.len() == PHONE_DIGITS + 1 && digits.starts_with('1') {
+        digits.remove(0);
+    }
+    digits.truncate(PHONE_DIGITS);
+    digits
+}
+
+// phone mask over however many digits there are so far
+// => anything that isn't a digit is dropped first, so slicing always lands on a char boundary
+pub fn format_phone(value: &str) -> String {
+    let digits = phone_digits(value);
+    match digits.len() {
+        0 => String::new(),
+        1..=3 => format!("({digits}"),
+        4..=6 => format!("({}) {}", &digits[..3], &digits[3..]),
+        _ => format!("({}) {}-{}", &digits[..3], &digits[3..6], &digits[6..]),
+    }
+}
+
+// character secret inputs draw in place of the real ones
+pub const MASK: char = '\u{2022}';
+
+// a secret with only its first `shown` characters readable
+pub fn partially_masked(value: &str, shown: usize) -> String {
+    value
+        .chars()
+        .enumerate()
+        .map(|(index, c)| if index < shown { c } else { MASK })
+        .collect()
+}
+
+// carry an edit made on a partially masked copy over to the real value
+// => both have the same length, so the changed span maps over character for character
+// => every mask looks alike, so an edit inside the masked run may fit several places: None then
+pub fn apply_edit(real: &str, shown: &str, edited: &str) -> Option<String> {
+    let real: Vec<char> = real.chars().collect();
+    let shown: Vec<char> = shown.chars().collect();
+    let edited: Vec<char> = edited.chars().collect();
+    if real.len() != shown.len() {
+        return Some(edited.into_iter().collect());
+    }
+    let from_start = splice(&real, &shown, &edited, true);
+    let from_end = splice(&real, &shown, &edited, false);
+    (from_start == from_end).then_some(from_start)
+}
+
+// `real` with the span that differs between `shown` and `edited` swapped in
+// => `prefix_first` decides which end gets the characters both ends could claim
+fn splice(real: &[char], shown: &[char], edited: &[char], prefix_first: bool) -> String {
+    let common = |a: &[char], b: &[char]| a.iter().zip(b).take_while(|(a, b)| a == b).count();
+    let common_end = |a: &[char], b: &[char]| a.iter().rev().zip(b.iter().rev()).take_while(|(a, b)| a == b).count();
+    let (prefix, suffix) = if prefix_first {
+        let prefix = common(shown, edited);
+        (prefix, common_end(&shown[prefix..], &edited[prefix..]))
+    } else {
+        let suffix = common_end(shown, edited);
+        (common(&shown[..shown.len() - suffix], &edited[..edited.len() - suffix]), suffix)
+    };
+    real[..prefix]
+        .iter()
+        .chain(&edited[prefix..edited.len() - suffix])
+        .chain(&real[real.len() - suffix..])
+        .collect()
+}
+
+// domains offered by the email domain combo box
+pub const COMMON_DOMAINS: [&str; 7] = [
+    "gmail.com",
+    "outlook.com",
+    "hotmail.com",
+    "yahoo.com",
+    "icloud.com",
+    "proton.me",
+    "example.com",
+];
+
+// local part and domain of an email => "jane@x.com" gives ("jane", "x.com")
+pub fn split_email(email: &str) -> (&str, &str) {
+    email.split_once('@').unwrap_or((email, ""))
+}
+
+// inverse of `split_email` => a full address typed or pasted into the local part brings its own domain,
+// a bare trailing '@' keeps the picked one
+pub fn compose_email(local: &str, domain: &str) -> String {
+    let domain = domain.trim_start_matches('@');
+    let (local, domain) = match local.split_once('@') {
+        Some((local, typed)) if !typed.is_empty() => (local, typed),
+        Some((local, _)) => (local, domain),
+        None => (local, domain),
+    };
+    if domain.is_empty() {
+        local.to_string()
+    } else {
+        format!("{local}@{domain}")
+    }
+}
+
+// empty a secret => its bytes are overwritten first so the old value doesn't linger in memory
+pub fn wipe(secret: &mut String) {
+    let len = secret.len();
+    secret.clear();
+    // same length, so this stays in the existing allocation
+    secret.extend(std::iter::repeat_n('\0', len));
+    std::hint::black_box(&*secret);
+    secret.clear();
+}
+
+// domains are case-insensitive => lowercase the part after the '@', the local part is left alone
+pub fn normalize_email(email: &str) -> String {
+    match email.split_once('@') {
+        Some((local, domain)) => format!("{local}@{}", domain.to_lowercase()),
+        None => email.to_string(),
+    }
+}
+
+// deliberately simple check => one '@' with something on both sides and a dotted domain
+pub fn is_valid_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && !email.contains(char::is_whitespace)
+                && domain.split('.').count() >= 2
+                && domain.split('.').all(|part| !part.is_empty())
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forms_render_in_the_configured_order() {
+        let order = [
+            FieldKind::Phone,
+            FieldKind::Password,
+            FieldKind::ConfirmPassword,
+            FieldKind::Email,
+            FieldKind::Username,
+        ];
+        let login = [FieldKind::Email, FieldKind::Password];
+        assert_eq!(ordered_fields(&order, &login), [FieldKind::Password, FieldKind::Email]);
+        assert_eq!(ordered_fields(&order, &FieldKind::ALL), order);
+        assert_eq!(ordered_fields(&FieldKind::ALL, &login), login);
+    }
+
+    #[test]
+    fn normalized_order_holds_every_field_once() {
+        let order = normalize_order(vec![FieldKind::Password, FieldKind::Email, FieldKind::Password]);
+        assert_eq!(
+            order,
+            [
+                FieldKind::Password,
+                FieldKind::Email,
+                FieldKind::Username,
+                FieldKind::ConfirmPassword,
+                FieldKind::Phone,
+            ]
+        );
+        assert_eq!(normalize_order(Vec::new()), FieldKind::ALL);
+    }
+
+    #[test]
+    fn moving_focus_blurs_the_previous_field() {
+        assert_eq!(blurred_field(Some(FieldKind::Email), Some(FieldKind::Password)), Some(FieldKind::Email));
+        assert_eq!(blurred_field(Some(FieldKind::Email), None), Some(FieldKind::Email));
+    }
+
+    #[test]
+    fn staying_or_starting_blurs_nothing() {
+        assert_eq!(blurred_field(Some(FieldKind::Email), Some(FieldKind::Email)), None);
+        assert_eq!(blurred_field(None, Some(FieldKind::Email)), None);
+        assert_eq!(blurred_field(None, None), None);
+    }
+
+    #[test]
+    fn confirmation_must_repeat_the_password() {
+        let form = |password: &'static str, confirmation: &'static str| {
+            move |kind| match kind {
+                FieldKind::Password => password,
+                FieldKind::ConfirmPassword => confirmation,
+                _ => "",
+            }
+        };
+        assert!(validate_in_form(FieldKind::ConfirmPassword, form("correct horse", "correct horse")).is_ok());
+        assert_eq!(
+            validate_in_form(FieldKind::ConfirmPassword, form("correct horse", "correct")),
+            Err(String::from("Passwords do not match."))
+        );
+        assert_eq!(
+            validate_in_form(FieldKind::ConfirmPassword, form("correct horse", "")),
+            Err(String::from(CONFIRM_REQUIRED))
+        );
+    }
+
+    #[test]
+    fn cap_length_counts_characters_not_bytes() {
+        let short = "é".repeat(MAX_INPUT_CHARS);
+        assert_eq!(cap_length(short.clone()), (short, false));
+
+        let (capped, truncated) = cap_length("é".repeat(MAX_INPUT_CHARS + 3));
+        assert!(truncated);
+        assert_eq!(capped.chars().count(), MAX_INPUT_CHARS);
+        assert_eq!(capped, "é".repeat(MAX_INPUT_CHARS));
+
+        // the cut lands on a boundary even with mixed widths
+        let (capped, _) = cap_length(format!("{}🦀🦀", "a".repeat(MAX_INPUT_CHARS - 1)));
+        assert_eq!(capped, format!("{}🦀", "a".repeat(MAX_INPUT_CHARS - 1)));
+    }
+
+    #[test]
+    fn split_email_cuts_at_the_first_at() {
+        assert_eq!(split_email("jane@example.com"), ("jane", "example.com"));
+        assert_eq!(split_email("jane"), ("jane", ""));
+        assert_eq!(split_email("jane@"), ("jane", ""));
+        assert_eq!(split_email(""), ("", ""));
+    }
+
+    #[test]
+    fn compose_email_rebuilds_the_address() {
+        assert_eq!(compose_email("jane", "example.com"), "jane@example.com");
+        // no domain picked yet => just the local part
+        assert_eq!(compose_email("jane", ""), "jane");
+        // a domain typed with its '@' => not doubled
+        assert_eq!(compose_email("jane", "@example.com"), "jane@example.com");
+        // a full address pasted into the local part => kept as is
+        assert_eq!(compose_email("jane@other.org", "example.com"), "jane@other.org");
+        // an '@' typed at the end of the local part => the picked domain still counts
+        assert_eq!(compose_email("jane@", "gmail.com"), "jane@gmail.com");
+        assert_eq!(compose_email("jane@", ""), "jane");
+    }
+
+    #[test]
+    fn split_and_compose_round_trip() {
+        for email in ["jane@example.com", "jane"] {
+            let (local, domain) = split_email(email);
+            assert_eq!(compose_email(local, domain), email);
+        }
+    }
+
+    const LOGIN: [FieldKind; 2] = [FieldKind::Email, FieldKind::Password];
+
+    #[test]
+    fn tab_cycles_forward_and_wraps() {
+        assert_eq!(cycle_field(&LOGIN, Some(FieldKind::Email), 1), Some(FieldKind::Password));
+        assert_eq!(cycle_field(&LOGIN, Some(FieldKind::Password), 1), Some(FieldKind::Email));
+    }
+
+    #[test]
+    fn shift_tab_cycles_backward_and_wraps() {
+        assert_eq!(cycle_field(&LOGIN, Some(FieldKind::Password), -1), Some(FieldKind::Email));
+        assert_eq!(cycle_field(&LOGIN, Some(FieldKind::Email), -1), Some(FieldKind::Password));
+    }
+
+    #[test]
+    fn cycling_with_nothing_focused_starts_at_an_end() {
+        assert_eq!(cycle_field(&LOGIN, None, 1), Some(FieldKind::Email));
+        assert_eq!(cycle_field(&LOGIN, None, -1), Some(FieldKind::Password));
+        // focus on a field the form doesn't render => same as nothing focused
+        assert_eq!(cycle_field(&LOGIN, Some(FieldKind::Phone), 1), Some(FieldKind::Email));
+        assert_eq!(cycle_field(&[], None, 1), None);
+    }
+
+    // "abcd" two characters into its reveal
+    const REAL: &str = "abcd";
+    const SHOWN: &str = "ab\u{2022}\u{2022}";
+
+    #[test]
+    fn typing_during_the_reveal_lands_on_the_real_value() {
+        assert_eq!(apply_edit(REAL, SHOWN, "ab\u{2022}\u{2022}e").as_deref(), Some("abcde"));
+        assert_eq!(apply_edit(REAL, SHOWN, "axb\u{2022}\u{2022}").as_deref(), Some("axbcd"));
+        // between two masks => the typed character tells where it went
+        assert_eq!(apply_edit(REAL, SHOWN, "ab\u{2022}x\u{2022}").as_deref(), Some("abcxd"));
+    }
+
+    #[test]
+    fn deleting_during_the_reveal() {
+        assert_eq!(apply_edit(REAL, SHOWN, "a\u{2022}\u{2022}").as_deref(), Some("acd"));
+        // which mask went can't be told => not guessed
+        assert_eq!(apply_edit(REAL, SHOWN, "ab\u{2022}"), None);
+        assert_eq!(apply_edit(REAL, SHOWN, "").as_deref(), Some(""));
+    }
+
+    #[test]
+    fn pasting_during_the_reveal() {
+        // over everything
+        assert_eq!(apply_edit(REAL, SHOWN, "pasted").as_deref(), Some("pasted"));
+        // over the readable part
+        assert_eq!(apply_edit(REAL, SHOWN, "xyz\u{2022}\u{2022}").as_deref(), Some("xyzcd"));
+        // over the masks
+        assert_eq!(apply_edit(REAL, SHOWN, "abxyz").as_deref(), Some("abxyz"));
+    }
+
+    #[test]
+    fn phone_mask_grows_with_the_digits() {
+        assert_eq!(format_phone(""), "");
+        assert_eq!(format_phone("12"), "(12");
+        assert_eq!(format_phone("123"), "(123");
+        assert_eq!(format_phone("1234"), "(123) 4");
+        assert_eq!(format_phone("123456"), "(123) 456");
+        assert_eq!(format_phone("1234567"), "(123) 456-7");
+        assert_eq!(format_phone("1234567890"), "(123) 456-7890");
+    }
+
+    #[test]
+    fn phone_mask_ignores_anything_but_digits() {
+        assert_eq!(format_phone("12€4"), "(124");
+        assert_eq!(format_phone("abc"), "");
+    }
+
+    #[test]
+    fn pasted_phone_numbers_keep_their_digits() {
+        assert_eq!(phone_digits("(123) 456-7890"), "1234567890");
+        assert_eq!(phone_digits("+1 (123) 456-7890"), "1234567890");
+        assert_eq!(phone_digits("11234567890"), "1234567890");
+        // a leading 1 that is part of the number stays
+        assert_eq!(phone_digits("1234"), "1234");
+        // longer than a number with its country code => cut, never shifted
+        assert_eq!(phone_digits("123456789012"), "1234567890");
+    }
+
+    #[test]
+    fn phone_needs_all_digits_or_nothing() {
+        assert!(validate(FieldKind::Phone, "").is_ok());
+        assert!(validate(FieldKind::Phone, "1234567890").is_ok());
+        assert!(validate(FieldKind::Phone, "123456").is_err());
+        assert!(validate(FieldKind::Phone, "12345abcde").is_err());
+    }
+
+    #[test]
+    fn normalize_email_lowercases_only_the_domain() {
+        assert_eq!(normalize_email("Jane.Doe@Example.COM"), "Jane.Doe@example.com");
+        assert_eq!(normalize_email("Jane@"), "Jane@");
+        // no domain yet => left as typed
+        assert_eq!(normalize_email("Jane"), "Jane");
+    }
+
+    #[test]
+    fn wipe_empties_the_secret_in_place() {
+        let mut secret = String::from("correct horse");
+        let capacity = secret.capacity();
+        wipe(&mut secret);
+        assert!(secret.is_empty());
+        assert_eq!(secret.capacity(), capacity);
+    }
+}
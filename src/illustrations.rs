@@ -0,0 +1,88 @@
+// small drawings built from styled containers => no canvas feature needed
+use iced::theme::Theme;
+use iced::widget::{container, Column, Row, Space};
+use iced::{Alignment, Background, Border, Color, Element, Length};
+
+// error page drawing => an exclamation mark on a soft disc, colors follow the theme
+pub fn error_illustration<'a, Message: 'a>(scale: f32) -> Element<'a, Message> {
+    let size = |value: f32| Length::Fixed(value * scale);
+    let shape = |width: f32, height: f32, part: Part| {
+        container(Space::new(size(width), size(height)))
+            .style(iced::theme::Container::Custom(Box::new(Shape { part, scale })))
+    };
+
+    let mark = Column::new()
+        .push(shape(14.0, 46.0, Part::Mark))
+        .push(shape(14.0, 14.0, Part::Mark))
+        .align_items(Alignment::Center)
+        .spacing(8.0 * scale);
+
+    container(mark)
+        .width(size(120.0))
+        .height(size(120.0))
+        .center_x()
+        .center_y()
+        .style(iced::theme::Container::Custom(Box::new(Shape { part: Part::Disc, scale })))
+        .into()
+}
+
+// success drawing => a checkmark laid out as cells on a 7 x 5 grid, drawn stroke order first
+// `progress` from 0 to 1 lights the cells one after another, so the check draws itself
+pub fn success_check<'a, Message: 'a>(progress: f32, scale: f32) -> Element<'a, Message> {
+    const PATH: [(usize, usize); 7] = [(0, 2), (1, 3), (2, 4), (3, 3), (4, 2), (5, 1), (6, 0)];
+    let drawn = (progress.clamp(0.0, 1.0) * PATH.len() as f32).ceil() as usize;
+    let cell = |column: usize, row: usize| {
+        let part = match PATH.iter().position(|&point| point == (column, row)) {
+            Some(order) if order < drawn => Part::Check,
+            _ => Part::Blank,
+        };
+        container(Space::new(Length::Fixed(10.0 * scale), Length::Fixed(10.0 * scale)))
+            .style(iced::theme::Container::Custom(Box::new(Shape { part, scale })))
+    };
+
+    let grid = (0..5).fold(Column::new().spacing(2.0 * scale), |grid, row| {
+        grid.push((0..7).fold(Row::new().spacing(2.0 * scale), |line, column| line.push(cell(column, row))))
+    });
+
+    container(grid)
+        .width(Length::Fixed(120.0 * scale))
+        .height(Length::Fixed(120.0 * scale))
+        .center_x()
+        .center_y()
+        .style(iced::theme::Container::Custom(Box::new(Shape { part: Part::Disc, scale })))
+        .into()
+}
+
+#[derive(Clone, Copy)]
+enum Part {
+    Disc,  // backdrop circle
+    Mark,  // the exclamation mark strokes
+    Check, // a drawn cell of the checkmark
+    Blank, // a checkmark cell not drawn (yet)
+}
+
+struct Shape {
+    part: Part,
+    scale: f32,
+}
+
+impl container::StyleSheet for Shape {
+    type Style = Theme;
+
+    fn appearance(&self, theme: &Self::Style) -> container::Appearance {
+        let palette = theme.extended_palette();
+        let (color, radius) = match self.part {
+            Part::Disc => (palette.background.weak.color, 60.0),
+            // a lighter red on dark backgrounds keeps the contrast
+            Part::Mark if palette.is_dark => (Color::from_rgb(0.95, 0.45, 0.45), 7.0),
+            Part::Mark => (crate::ERROR_COLOR, 7.0),
+            Part::Check => (palette.success.base.color, 3.0),
+            Part::Blank => return container::Appearance::default(),
+        };
+        container::Appearance {
+            background: Some(Background::Color(color)),
+            border: Border::with_radius(radius * self.scale),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// debug-only keystroke to view latency meter => logs a rolling average to stderr
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+// samples in the rolling average
+const WINDOW: usize = 20;
+
+#[derive(Debug, Default)]
+pub struct LatencyMeter {
+    keystroke_at: Option<Instant>, // keystroke waiting for its view
+    samples: VecDeque<Duration>,
+}
+
+impl LatencyMeter {
+    // a keystroke message arrived in `update`
+    pub fn keystroke(&mut self) {
+        self.keystroke_at = Some(Instant::now());
+    }
+
+    // a view was produced => closes the pending keystroke, if any
+    pub fn view_produced(&mut self) {
+        let Some(keystroke_at) = self.keystroke_at.take() else {
+            return;
+        };
+        self.samples.push_back(keystroke_at.elapsed());
+        if self.samples.len() > WINDOW {
+            self.samples.pop_front();
+        }
+        eprintln!(
+            "[latency] keystroke -> view: {:?} (avg of last {}: {:?})",
+            self.samples.back().copied().unwrap_or_default(),
+            self.samples.len(),
+            self.average(),
+        );
+    }
+
+    pub fn average(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+}
@@ -1,34 +1,243 @@
 // modules
+mod api;
+mod cache;
+mod cli;
+mod demo;
+mod error_log;
+mod focus;
+mod form;
+mod illustrations;
+#[cfg(debug_assertions)]
+mod latency;
+mod notifications;
+mod preset;
+mod search;
+mod session;
+mod settings;
+mod shortcuts;
+mod steps;
+mod timer;
+mod window_state;
+
+use std::collections::HashMap;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
 #[allow(unused_imports)]
 use iced::alignment::{Horizontal, Vertical};
 use iced::theme::Theme;
 use iced::{Background, Length, Padding, Shadow, Vector};
-use iced::widget::{button, container, text, Button, Column, Container, TextInput, Row};
-use iced::{Alignment, Border, Element, Sandbox, Settings};
+use iced::widget::{button, checkbox, combo_box, container, mouse_area, pick_list, progress_bar, radio, scrollable, slider, text, text_input, Space, Button, Column, Container, TextInput, Row};
+use iced::{executor, window, Alignment, Application, Border, Command, Element, Settings, Subscription};
+
+use api::{ApiError, CheckResult, RegisterEvent};
+use cache::TtlCache;
+use error_log::{ErrorLog, ErrorSource};
+use focus::Modality;
+use form::FieldKind;
+use notifications::Notifications;
+use preset::Preset;
+use session::Session;
+use steps::StepState;
+use settings::{AppSettings, ContentAlignment, DisabledLook, InitialFocus, LoginLayout};
+use window_state::WindowState;
 
 // Entry point
-pub fn main() -> iced::Result {
-    RustUI::run(Settings::default())
+// => iced tries the wgpu (GPU) renderer first and falls back to the tiny-skia software
+//    renderer on its own; if neither can start we explain why and exit with a non-zero code
+pub fn main() -> ExitCode {
+    sanitize_backend_env();
+
+    // read once => the window and the app start from the same copy
+    let startup = Startup {
+        flags: cli::Flags::from_args(),
+        settings: AppSettings::load(),
+        geometry: WindowState::load(),
+    };
+    let decorations = !startup.settings.borderless;
+    // restore the last geometry => saved by the app itself when the window closes
+    let size = startup.geometry.size;
+    let position = startup.geometry.position();
+
+    let mut settings = Settings::with_flags(startup);
+    settings.window.decorations = decorations;
+    settings.window.size = size;
+    settings.window.position = position;
+    settings.window.exit_on_close_request = false;
+
+    match RustUI::run(settings) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(iced::Error::GraphicsCreationFailed(error)) => {
+            eprintln!("error: could not start a renderer: {error}");
+            eprintln!(
+                "hint: no usable GPU or software renderer was found. On a headless machine or VM, \
+                 make sure a display server is running, or force the software renderer with \
+                 ICED_BACKEND=tiny-skia."
+            );
+            ExitCode::FAILURE
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// iced panics on unknown ICED_BACKEND values => drop them so the default fallback order applies
+fn sanitize_backend_env() {
+    let Ok(backends) = std::env::var("ICED_BACKEND") else {
+        return;
+    };
+    let valid = backends
+        .split(',')
+        .map(str::trim)
+        .all(|backend| backend == "wgpu" || backend == "tiny-skia");
+    if !valid {
+        eprintln!("warning: ignoring unknown ICED_BACKEND `{backends}` (expected wgpu and/or tiny-skia)");
+        std::env::remove_var("ICED_BACKEND");
+    }
 }
 
 struct RustUI {
     // main variables used in making the instance
     theme: Theme,
     page: Page, // to keep track of pages
+    history: Vec<Page>, // previously visited pages => popped by Back
     login_field: LoginField,
+    register_field: RegisterField,
+    settings: AppSettings,
+    saved_settings: AppSettings, // baseline snapshot => settings differ from it while unsaved
+    pending_navigation: Option<Message>, // navigation held back until changes are discarded
+    settings_query: String,             // search box filter on the settings page
+    show_help: bool,                    // shortcut overlay visible
+    show_errors: bool,                  // recent errors overlay visible
+    notifications: Notifications,       // notices behind the footer bell
+    show_notifications: bool,           // notification dropdown open
+    presets: Vec<Preset>,               // saved form values, loaded from disk
+    demo: Option<usize>,                // next demo script step while demo mode runs
+    hovered_link: Option<&'static str>, // link under the mouse => gets underlined
+    decorated: bool,                    // whether the window currently has OS decorations
+    geometry: WindowState,              // current window position and size => saved on close
+    email_domains: combo_box::State<String>, // typeahead options of the login email domain
+    clipboard_email: Option<String>,    // valid email found in the clipboard => offered as a paste chip
+    paste_dismissed: bool,              // paste chip dismissed => not offered again this session
+    password_visible: bool,             // secret fields shown in plain text
+    reveal: Option<(Instant, f32)>,     // reveal animation start and fraction of characters shown
+    switch_animation: Option<(&'static str, Instant, f32)>, // toggle switch whose knob is sliding => (label, start, progress)
+    label_float: HashMap<FieldKind, f32>, // floating label positions => 0 = placeholder, 1 = label above the field
+    label_tick: Option<Instant>,        // last floating label frame => None once every label has settled
+    success: Option<(Instant, f32, Message)>, // success checkmark => (start, stroke progress, navigation once it's done)
+    preset_name: String,                // name for the next saved preset
+    #[cfg(debug_assertions)]
+    latency: std::cell::RefCell<latency::LatencyMeter>, // keystroke to view timing => view only gets &self
+    errors: Vec<(FieldKind, String)>, // validation errors in field order
+    focused: Option<FieldKind>,        // field that last received input or focus
+    autofocused: bool,                 // focus came from opening the page => leaving it untouched isn't a blur
+    edit_generation: u64,              // bumped on every debounced edit => stale timers are ignored
+    unsettled: Option<FieldKind>,      // field edited since its live validation last ran
+    modality: Modality,                // last input modality => decides focus ring visibility
+    busy: bool,                        // a login or register request is in flight
+    progress: Option<f32>,             // known progress of the request in flight, 0.0 - 1.0
+    spinner_frame: usize,              // animation frame for requests without known progress
+    toast: Option<String>,             // short notice shown above the footer
+    session: Option<Session>,          // the signed in user, if any
+    now: Instant,                      // clock for relative times => refreshed by Tick
+    activity: Vec<String>,             // recent notable events, newest last
+    email_checks: TtlCache<CheckResult>, // availability answers keyed by the exact email
+    email_check: Option<(String, Option<Result<CheckResult, ApiError>>)>, // latest check, None while waiting => shown while it matches the field
+    error_log: ErrorLog,               // recent validation, request and storage errors
+    in_flight: Option<Operation>,      // login or register request currently running
+    failure: Option<Failure>,          // unexpected error shown on the error page
 }
 
+// trailing dots of the busy spinner
+const SPINNER_FRAMES: [&str; 4] = ["", ".", "..", "..."];
+
+// email availability cache limits
+const EMAIL_CHECK_CAPACITY: usize = 32;
+const EMAIL_CHECK_TTL: Duration = Duration::from_secs(60);
+
+// how many activity entries are kept for the debug report
+const ACTIVITY_LIMIT: usize = 20;
+
+// how long revealing a password takes, left to right
+const REVEAL_DURATION: Duration = Duration::from_millis(400);
+
+// how long a toggle switch knob takes to slide across
+const SWITCH_DURATION: Duration = Duration::from_millis(150);
+
+// how long a floating label takes to rise out of its field
+const LABEL_FLOAT_DURATION: Duration = Duration::from_millis(180);
+
+// success checkmark => how long the stroke takes to draw, then how long it stays before moving on
+const CHECK_DRAW_DURATION: Duration = Duration::from_millis(400);
+const CHECK_HOLD_DURATION: Duration = Duration::from_millis(300);
+
+// how many errors the recent errors viewer keeps
+const ERROR_LOG_CAPACITY: usize = 50;
+
 // separate struct for login field
 struct LoginField {
     email: String,
     password: String,
 }
 
+// separate struct for register field
+#[derive(Default, PartialEq)]
+struct RegisterField {
+    username: String,
+    email: String,
+    password: String,
+    confirm_password: String,
+    phone: String, // raw digits => formatted only when shown
+}
+
 // enum for Page => Each var inside Page will create a new view/page
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Page {
     Login,
     Register,
+    Settings,
+    Home,
+    Error,
+}
+
+impl Page {
+    // fields each form renders => the on-screen order comes from the settings
+    fn form_fields(&self) -> &'static [FieldKind] {
+        match self {
+            Page::Login => &[FieldKind::Email, FieldKind::Password],
+            Page::Register => &[
+                FieldKind::Username,
+                FieldKind::Email,
+                FieldKind::Password,
+                FieldKind::ConfirmPassword,
+                FieldKind::Phone,
+            ],
+            Page::Settings | Page::Home | Page::Error => &[],
+        }
+    }
+
+    // message run by the page's main button
+    fn primary_action(&self) -> Option<Message> {
+        match self {
+            Page::Login => Some(Message::LoginSubmit),
+            Page::Register => Some(Message::RegisterSubmit),
+            Page::Settings => Some(Message::SaveSettings),
+            Page::Home => None,
+            Page::Error => Some(Message::Retry),
+        }
+    }
+
+    // pages reachable by route name => Home needs a session so it is never routed to
+    fn from_route(route: &str) -> Option<Page> {
+        match route.to_ascii_lowercase().as_str() {
+            "login" => Some(Page::Login),
+            "register" => Some(Page::Register),
+            "settings" => Some(Page::Settings),
+            _ => None,
+        }
+    }
 }
 
 // define message => similar to callbacks
@@ -36,24 +245,133 @@ enum Page {
 enum Message {
     ToggleTheme,                      // used to toggle Light / Dark theme
     LoginSubmit,                      // to trigger to print email + password to console
+    LoginResult(Result<String, ApiError>), // answer from the backend for a login request
+    Register(RegisterEvent),          // progress and result of creating an account
+    EmailChecked(String, Result<CheckResult, ApiError>), // availability answer for an email
+    RetryEmailCheck,                  // asks again after a failed availability check
+    Spin,                             // advances the busy spinner
+    Retry,                            // re-issues the request that failed unexpectedly
+    DemoStep,                         // runs the next scripted demo message
+    StopDemo,                         // real input arrived => leave demo mode
+    LinkHovered(Option<&'static str>), // mouse entered / left a link
+    Logout,                           // ends the session and goes back to login
+    Tick(Instant),                    // periodic tick => refreshes relative times
+    StaySignedIn,                     // expiry banner clicked => restarts the session countdown
+    ModalityChanged(Modality),        // user switched between mouse and keyboard
+    RegisterSubmit,                   // validates the register form
     Router(String),                   // change the page depending on route
-    LoginFieldChange(String, String), // updates the input fields for email and password
+    Back,                             // return to the previous page
+    UsernameChanged(String),          // updates the username input field
+    EmailChanged(String),             // updates the email input field
+    PasswordChanged(String),          // updates the password input field
+    ConfirmPasswordChanged(String),   // updates the confirm password input field
+    PhoneChanged(String),             // updates the phone field => keeps only the digits
+    MaskedEdit(FieldKind, String, String), // edit to a half revealed secret => (field, shown, edited)
+    TogglePasswordVisibility,         // shows or hides the secret fields
+    RevealTick(Instant),              // advances the reveal animation
+    ReduceMotionToggled(bool),        // updates the reduce motion setting
+    Switch(&'static str, Box<Message>), // toggle switch flipped => slides its knob, then runs the message
+    SwitchTick(Instant),              // advances the toggle switch animation
+    LabelTick(Instant),               // moves floating labels toward their resting place
+    SuccessTick(Instant),             // draws the success checkmark => navigates once it is done
+    ShowHome,                         // signed in => the home page replaces the login history
+    EmailLocalChanged(String),        // login email => the part before the '@'
+    EmailDomainChanged(String),       // login email => the part after the '@', typed or picked
+    FieldSubmitted(FieldKind),        // enter pressed inside a field => focus the next one
+    EnterPressed,                     // enter pressed outside of any input
+    FocusEmail,                       // focus and select the email field
+    CycleFocus(isize),                // Tab (1) / Shift+Tab (-1) through the form fields
+    KeyPressed(iced::keyboard::Key, iced::keyboard::Modifiers, bool), // any key press => (key, modifiers, already handled by a widget)
+    ToggleHelp,                       // shows or hides the shortcut overlay
+    ToggleErrors,                     // shows or hides the recent errors overlay
+    ToggleNotifications,              // opens the bell dropdown => marks everything read
+    Dismiss,                          // Escape => closes only the topmost overlay
+    PresetNameChanged(String),        // updates the name for a new preset
+    SavePreset,                       // stores the current non-secret form values
+    PresetSelected(String),           // fills the form from a saved preset
+    EnterSubmitsToggled(bool),        // updates the enter-anywhere setting
+    BorderlessToggled(bool),          // updates the borderless window setting
+    LowercaseDomainToggled(bool),     // updates the email domain lowercasing setting
+    ClearPasswordToggled(bool),       // updates the clear password on failed login setting
+    DisabledLookSelected(DisabledLook), // updates the disabled submit button look
+    DebounceChanged(u32),             // updates the input debounce setting
+    AlignmentSelected(ContentAlignment), // updates the page content alignment setting
+    LoginLayoutSelected(LoginLayout), // updates the login form layout setting
+    InitialFocusSelected(InitialFocus), // updates which field is focused when a page opens
+    RequestTimeoutChanged(u32),       // updates the backend request timeout setting
+    InputSettled(u64),                // typing paused => run the deferred live validation
+    DragWindow,                       // title strip pressed => move the window
+    MinimizeWindow,                   // custom minimize button in the title strip
+    CloseWindow,                      // title strip or OS close button => saves the geometry first
+    WindowFocused,                    // the window gained focus => look at the clipboard again
+    WindowUnfocused,                  // the window lost focus => settings get saved
+    WindowMoved(iced::Point),         // new window position
+    WindowResized(iced::Size),        // new window size
+    ClipboardRead(Option<String>),    // clipboard contents for the paste chip
+    PasteDetectedEmail,               // fills the email field from the clipboard
+    DismissPasteChip,                 // hides the paste chip for the rest of the session
+    WindowMenu,                       // title strip right-clicked => OS window menu
+    MoveField(FieldKind, isize),      // reorders a field in the settings list
+    CopyDebugReport,                  // copies a redacted debug report to the clipboard
+    CopyEmail,                        // copies the signed in email from the home page
+    ReadOnlyEdited,                   // typing into a read-only field => ignored
+    TextScaleChanged(f32),            // updates the text scale setting
+    SaveSettings,                     // persists the edited settings
+    SettingsQueryChanged(String),     // updates the settings search box
+    DiscardChanges,                   // drops unsaved edits and continues the held navigation
+    KeepEditing,                      // cancels the held navigation
+}
+
+// async requests that can be retried from the error page
+#[derive(Debug, Clone)]
+enum Operation {
+    Login { email: String, password: String },
+    Register { email: String, password: String },
+    CheckEmail(String),
+}
+
+impl Operation {
+    // overwrite the password a login keeps for retrying => the login form has to supply it again
+    fn wipe_password(&mut self) {
+        if let Operation::Login { password, .. } = self {
+            form::wipe(password);
+        }
+    }
+
+    // a login can't be sent without a password => an empty one was wiped
+    fn needs_password(&self) -> bool {
+        matches!(self, Operation::Login { password, .. } if password.is_empty())
+    }
+}
+
+// an unexpected request error together with the request that hit it
+#[derive(Debug, Clone)]
+struct Failure {
+    error: ApiError,
+    operation: Operation,
+}
+
+// what main() reads before the window opens => handed on to the app
+#[derive(Debug, Default)]
+struct Startup {
+    flags: cli::Flags,
+    settings: AppSettings,
+    geometry: WindowState,
 }
 
-// now we implement a Sandbox for RustUI
-impl Sandbox for RustUI {
+// now we implement an Application for RustUI
+impl Application for RustUI {
+    type Executor = executor::Default;
     type Message = Message;
+    type Theme = Theme;
+    type Flags = Startup;
 
     // app constructor
-    fn new() -> Self {
-        Self {
-            theme: Theme::Dark,
-            page: Page::Login,
-            login_field: LoginField {
-                email: String::new(),
-                password: String::new(),
-            },
-        }
+    fn new(startup: Startup) -> (Self, Command<Message>) {
+        let mut app = Self::with_state(startup.flags, startup.settings, preset::load(), startup.geometry);
+        // the focus event may already have passed => check the clipboard once at startup
+        let command = Command::batch([app.read_clipboard(), app.focus_on_load()]);
+        (app, command)
     }
 
     // defines app title
@@ -67,34 +385,619 @@ impl Sandbox for RustUI {
     }
 
     // define the update method
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Command<Message> {
+        #[cfg(debug_assertions)]
+        if matches!(
+            message,
+            Message::UsernameChanged(_)
+                | Message::EmailChanged(_)
+                | Message::PasswordChanged(_)
+                | Message::ConfirmPasswordChanged(_)
+                | Message::PhoneChanged(_)
+        ) {
+            self.latency.get_mut().keystroke();
+        }
+
+        // a pasted wall of text would make every keystroke clone and lay it out => cap it up front
+        let message = self.cap_input(message);
+
         match message {
             Message::ToggleTheme => {
                 self.theme = if self.theme == Theme::Light {
                     Theme::Dark
                 } else {
                     Theme::Light
+                };
+                self.notifications.push(format!("Theme changed to {:?}.", self.theme));
+            }
+            Message::UsernameChanged(username) => {
+                self.register_field.username = username;
+                return self.field_edited(FieldKind::Username);
+            }
+            Message::EmailChanged(email) => {
+                let email = if self.settings.lowercase_email_domain {
+                    form::normalize_email(&email)
+                } else {
+                    email
+                };
+                match self.page {
+                    Page::Register => self.register_field.email = email,
+                    _ => self.login_field.email = email,
+                }
+                return self.field_edited(FieldKind::Email);
+            }
+            Message::PasswordChanged(password) => {
+                match self.page {
+                    Page::Register => self.register_field.password = password,
+                    _ => self.login_field.password = password,
+                }
+                return self.field_edited(FieldKind::Password);
+            }
+            // the login email is stored whole => the split inputs rebuild it from both parts
+            Message::EmailLocalChanged(local) => {
+                let (_, domain) = form::split_email(&self.login_field.email);
+                let email = form::compose_email(&local, domain);
+                return self.update(Message::EmailChanged(email));
+            }
+            Message::EmailDomainChanged(domain) => {
+                let (local, _) = form::split_email(&self.login_field.email);
+                let email = form::compose_email(local, &domain);
+                return self.update(Message::EmailChanged(email));
+            }
+            // typing during the reveal => apply it to the real value and finish revealing
+            // => an edit that can't be placed inside the masked run is dropped, the full text is shown instead
+            Message::MaskedEdit(kind, shown, edited) => {
+                let value = form::apply_edit(self.field_value(kind), &shown, &edited);
+                self.reveal = None;
+                let Some(value) = value else {
+                    return Command::none();
+                };
+                return self.update(match kind {
+                    FieldKind::ConfirmPassword => Message::ConfirmPasswordChanged(value),
+                    _ => Message::PasswordChanged(value),
+                });
+            }
+            Message::TogglePasswordVisibility => {
+                self.password_visible = !self.password_visible;
+                self.reveal = (self.password_visible && !self.settings.reduce_motion)
+                    .then(|| (Instant::now(), 0.0));
+            }
+            Message::RevealTick(now) => {
+                if let Some((started, _)) = self.reveal {
+                    let fraction = now.saturating_duration_since(started).as_secs_f32()
+                        / REVEAL_DURATION.as_secs_f32();
+                    self.reveal = (fraction < 1.0).then_some((started, fraction));
+                }
+            }
+            Message::ReduceMotionToggled(enabled) => self.settings.reduce_motion = enabled,
+            Message::Switch(label, message) => {
+                let command = self.update(*message);
+                // checked after the message => turning reduce motion on already snaps
+                self.switch_animation = (!self.settings.reduce_motion).then(|| (label, Instant::now(), 0.0));
+                return command;
+            }
+            Message::SwitchTick(now) => {
+                if let Some((label, started, _)) = self.switch_animation {
+                    let progress = now.saturating_duration_since(started).as_secs_f32()
+                        / SWITCH_DURATION.as_secs_f32();
+                    self.switch_animation = (progress < 1.0).then_some((label, started, progress));
+                }
+            }
+            Message::SuccessTick(now) => {
+                if let Some((started, progress, _)) = self.success.as_mut() {
+                    let elapsed = now.saturating_duration_since(*started);
+                    *progress = elapsed.as_secs_f32() / CHECK_DRAW_DURATION.as_secs_f32();
+                    if elapsed >= CHECK_DRAW_DURATION + CHECK_HOLD_DURATION {
+                        if let Some((_, _, next)) = self.success.take() {
+                            return self.update(next);
+                        }
+                    }
+                }
+            }
+            // logged out while the checkmark was showing => stay where we are
+            Message::ShowHome if self.session.is_some() => {
+                self.page = Page::Home;
+                self.history.clear();
+                self.page_changed();
+            }
+            Message::ShowHome => {}
+            Message::LabelTick(now) => {
+                let step = self.label_tick.map_or(0.0, |last| {
+                    now.saturating_duration_since(last).as_secs_f32() / LABEL_FLOAT_DURATION.as_secs_f32()
+                });
+                for kind in self.visible_fields() {
+                    let target = self.label_target(kind);
+                    let position = self.label_float.entry(kind).or_insert(0.0);
+                    *position = if target > *position {
+                        (*position + step).min(target)
+                    } else {
+                        (*position - step).max(target)
+                    };
+                }
+                self.label_tick = self.labels_moving().then_some(now);
+            }
+            Message::ConfirmPasswordChanged(confirmation) => {
+                self.register_field.confirm_password = confirmation;
+                return self.field_edited(FieldKind::ConfirmPassword);
+            }
+            Message::PhoneChanged(text) => {
+                // already complete => another digit would pass for a country code and shift the rest
+                let typed = text.chars().filter(char::is_ascii_digit).count();
+                if self.register_field.phone.len() == form::PHONE_DIGITS && typed > form::PHONE_DIGITS {
+                    return Command::none();
+                }
+                let mut digits = form::phone_digits(&text);
+                // deleting a mask character leaves the digits alone => drop the digit before it instead
+                let shown = form::format_phone(&self.register_field.phone);
+                if digits == self.register_field.phone && text.chars().count() < shown.chars().count() {
+                    digits.pop();
+                }
+                self.register_field.phone = digits;
+                return self.field_edited(FieldKind::Phone);
+            }
+            Message::FieldSubmitted(kind) => {
+                let fields = self.visible_fields();
+                let next = form::next_field(&fields, kind).filter(|_| !self.settings.enter_submits);
+                return match (next, self.page.primary_action()) {
+                    (Some(next), _) => Command::batch([
+                        self.move_focus(Some(next)),
+                        text_input::focus(next.input_id()),
+                    ]),
+                    (None, Some(action)) => self.update(action),
+                    (None, None) => Command::none(),
+                };
+            }
+            Message::EnterPressed => {
+                let enabled = self.settings.enter_submits && self.pending_navigation.is_none();
+                if let Some(action) = self.page.primary_action().filter(|_| enabled) {
+                    return self.update(action);
+                }
+            }
+            Message::FocusEmail => {
+                // only when the current page shows an email field
+                if self.visible_fields().contains(&FieldKind::Email) && self.pending_navigation.is_none() {
+                    let id = FieldKind::Email.input_id();
+                    return Command::batch([
+                        self.move_focus(Some(FieldKind::Email)),
+                        text_input::focus(id.clone()),
+                        text_input::select_all(id),
+                    ]);
+                }
+            }
+            Message::CycleFocus(offset) => {
+                let fields = self.visible_fields();
+                let next = form::cycle_field(&fields, self.focused, offset);
+                if let Some(next) = next.filter(|_| self.pending_navigation.is_none()) {
+                    return Command::batch([self.move_focus(Some(next)), text_input::focus(next.input_id())]);
+                }
+            }
+            Message::KeyPressed(key, modifiers, captured) => {
+                if let Some(shortcut) = shortcuts::find(&self.page, &key, modifiers, captured) {
+                    return self.update(shortcut_message(shortcut.action));
+                }
+            }
+            // one overlay per press => discard dialog, notifications, help, errors, then the toast
+            Message::Dismiss => {
+                if self.pending_navigation.is_some() {
+                    return self.update(Message::KeepEditing);
+                } else if self.show_notifications {
+                    self.show_notifications = false;
+                } else if self.show_help {
+                    self.show_help = false;
+                } else if self.show_errors {
+                    self.show_errors = false;
+                } else {
+                    self.toast = None;
+                }
+            }
+            Message::ToggleHelp => self.show_help = !self.show_help,
+            Message::ToggleErrors => self.show_errors = !self.show_errors,
+            Message::ToggleNotifications => {
+                self.show_notifications = !self.show_notifications;
+                self.notifications.mark_all_read();
+            }
+            Message::PresetNameChanged(name) => self.preset_name = name,
+            Message::SavePreset => {
+                let fields = self
+                    .visible_fields()
+                    .into_iter()
+                    .map(|kind| (kind, self.field_value(kind).to_string()));
+                let preset = Preset::new(&self.preset_name, fields);
+                if preset.name.is_empty() {
+                    return Command::none();
+                }
+                // saving under an existing name replaces that preset
+                self.presets.retain(|existing| existing.name != preset.name);
+                self.toast = Some(format!("Saved preset \"{}\".", preset.name));
+                self.presets.push(preset);
+                self.preset_name.clear();
+                if let Err(error) = preset::save(&self.presets) {
+                    eprintln!("failed to save presets: {error}");
+                    self.error_log.push(ErrorSource::Storage, format!("failed to save presets: {error}"));
+                }
+            }
+            Message::PresetSelected(name) => {
+                if let Some(preset) = self.presets.iter().find(|preset| preset.name == name).cloned() {
+                    for kind in self.visible_fields() {
+                        if let Some(value) = preset.value(kind) {
+                            self.fill_field(kind, value);
+                        }
+                    }
+                    self.errors.clear();
+                }
+            }
+            Message::EnterSubmitsToggled(enabled) => self.settings.enter_submits = enabled,
+            Message::LowercaseDomainToggled(enabled) => self.settings.lowercase_email_domain = enabled,
+            Message::ClearPasswordToggled(enabled) => self.settings.clear_password_on_failure = enabled,
+            Message::DisabledLookSelected(look) => self.settings.disabled_look = look,
+            Message::AlignmentSelected(alignment) => self.settings.alignment = alignment,
+            Message::LoginLayoutSelected(layout) => self.settings.login_layout = layout,
+            Message::InitialFocusSelected(focus) => self.settings.initial_focus = focus,
+            Message::RequestTimeoutChanged(secs) => {
+                self.settings.request_timeout_secs =
+                    secs.clamp(settings::MIN_REQUEST_TIMEOUT_SECS, settings::MAX_REQUEST_TIMEOUT_SECS);
+            }
+            Message::DebounceChanged(ms) => self.settings.debounce_ms = ms.min(settings::MAX_DEBOUNCE_MS),
+            Message::InputSettled(generation) => {
+                if generation == self.edit_generation {
+                    if let Some(kind) = self.unsettled.take() {
+                        self.live_validate(kind);
+                    }
+                }
+            }
+            Message::BorderlessToggled(enabled) => {
+                self.settings.borderless = enabled;
+                return self.sync_decorations();
+            }
+            // wayland only lets a drag start from a live button press => drag on press, not on move
+            Message::DragWindow => return window::drag(window::Id::MAIN),
+            Message::MinimizeWindow => return window::minimize(window::Id::MAIN, true),
+            Message::WindowMoved(position) => self.geometry.position = Some(position),
+            Message::WindowResized(size) => self.geometry.size = size,
+            // the title strip button and the OS close button both end up here
+            Message::CloseWindow => {
+                self.autosave_settings();
+                if let Err(error) = self.geometry.save() {
+                    eprintln!("failed to save the window position: {error}");
+                }
+                return window::close(window::Id::MAIN);
+            }
+            // winit only implements the window menu on windows => elsewhere right-click does nothing
+            Message::WindowMenu if cfg!(target_os = "windows") => {
+                return window::show_system_menu(window::Id::MAIN);
+            }
+            Message::WindowMenu => {}
+            Message::WindowFocused => return self.read_clipboard(),
+            Message::WindowUnfocused => {
+                self.autosave_settings();
+            }
+            Message::ClipboardRead(contents) => {
+                self.clipboard_email = contents
+                    .map(|contents| contents.trim().to_string())
+                    .filter(|contents| form::is_valid_email(contents));
+            }
+            Message::PasteDetectedEmail => {
+                if let Some(email) = self.clipboard_email.clone() {
+                    return Command::batch([
+                        self.update(Message::EmailChanged(email)),
+                        text_input::focus(FieldKind::Email.input_id()),
+                    ]);
+                }
+            }
+            Message::DismissPasteChip => self.paste_dismissed = true,
+            Message::LoginSubmit | Message::RegisterSubmit => {
+                // a login is already in flight or its checkmark is still showing => ignore the duplicate
+                if self.busy || self.success.is_some() {
+                    self.toast = Some(String::from("Already submitting..."));
+                    return Command::none();
+                }
+                let fields = self.visible_fields();
+                self.errors = form::validate_fields(&fields, |kind| self.field_value(kind));
+                for (kind, error) in &self.errors {
+                    self.error_log.push(ErrorSource::Validation, format!("{}: {error}", kind.label()));
+                }
+                // focus the first invalid field in the configured order
+                if let Some((kind, _)) = self.errors.first() {
+                    self.focused = Some(*kind);
+                    return text_input::focus(kind.input_id());
+                }
+                return self.start(if matches!(message, Message::LoginSubmit) {
+                    Operation::Login {
+                        email: self.login_field.email.clone(),
+                        password: self.login_field.password.clone(),
+                    }
+                } else {
+                    Operation::Register {
+                        email: self.register_field.email.clone(),
+                        password: self.register_field.password.clone(),
+                    }
+                });
+            }
+            Message::Retry => {
+                if let Some(failure) = self.failure.take() {
+                    let back = self.update(Message::Back);
+                    if failure.operation.needs_password() {
+                        let focus = self.move_focus(Some(FieldKind::Password));
+                        return Command::batch([back, focus, text_input::focus(FieldKind::Password.input_id())]);
+                    }
+                    return Command::batch([back, self.start(failure.operation)]);
+                }
+            }
+            Message::Register(RegisterEvent::Progress(done, total)) => {
+                self.progress = Some(done as f32 / total as f32);
+            }
+            Message::Register(RegisterEvent::Finished(result)) => {
+                self.busy = false;
+                self.progress = None;
+                let operation = self.in_flight.take();
+                match result {
+                    Ok(email) => {
+                        self.log_activity("account created");
+                        self.register_field = RegisterField::default();
+                        self.login_field.email = email;
+                        self.toast = Some(String::from("Account created. You can log in now."));
+                        self.success = Some((Instant::now(), 0.0, Message::Router("Login".to_string())));
+                    }
+                    Err(error) => {
+                        self.log_activity("account creation failed");
+                        self.request_failed(error, operation);
+                    }
+                }
+            }
+            // a failed check is shown next to the field with its own retry => no trip to the error page
+            Message::EmailChecked(email, result) => {
+                match &result {
+                    Ok(check) => self.email_checks.insert(email.clone(), *check, Instant::now()),
+                    Err(error) => self.error_log.push(ErrorSource::Request, error.to_string()),
+                }
+                // an answer for an older value must not replace the check that is waiting
+                if let Some((pending, _)) = &self.email_check {
+                    if *pending == email {
+                        self.email_check = Some((email, Some(result)));
+                    }
+                }
+            }
+            Message::RetryEmailCheck => return self.check_email_availability(),
+            Message::DemoStep => {
+                if let Some(step) = self.demo {
+                    let script = demo::script();
+                    self.demo = Some((step + 1) % script.len());
+                    if let Some(message) = script.into_iter().nth(step).flatten() {
+                        return self.update(message);
+                    }
+                }
+            }
+            Message::StopDemo => {
+                if self.demo.take().is_some() {
+                    self.toast = Some(String::from("Demo stopped."));
+                }
+            }
+            Message::LinkHovered(link) => self.hovered_link = link,
+            Message::Spin => self.spinner_frame = (self.spinner_frame + 1) % SPINNER_FRAMES.len(),
+            Message::LoginResult(result) => {
+                self.busy = false;
+                self.toast = None;
+                let mut operation = self.in_flight.take();
+                match result {
+                    Ok(email) => {
+                        let session = Session::new(email);
+                        self.now = session.logged_in_at;
+                        self.session = Some(session);
+                        self.login_field.password.clear();
+                        self.log_activity("login succeeded");
+                        self.success = Some((Instant::now(), 0.0, Message::ShowHome));
+                    }
+                    Err(error) => {
+                        self.log_activity("login failed");
+                        // every kept copy goes => the field and the request held for retrying
+                        if self.settings.clear_password_on_failure {
+                            form::wipe(&mut self.login_field.password);
+                            if let Some(operation) = operation.as_mut() {
+                                operation.wipe_password();
+                            }
+                        }
+                        self.request_failed(error, operation);
+                    }
+                }
+            }
+            Message::Tick(now) => {
+                self.now = now;
+                if let Some(session) = self.session.as_mut() {
+                    let remaining = session.expires_at().saturating_duration_since(now);
+                    if !session.expiry_notified && remaining <= session::EXPIRY_NOTICE {
+                        session.expiry_notified = true;
+                        self.notifications.push("Session expiring soon.");
+                    }
+                    if session.warning_due(now) {
+                        session.warned = true;
+                    }
+                    if remaining.is_zero() {
+                        self.toast = Some(String::from("Session expired. Please log in again."));
+                        return self.update(Message::Logout);
+                    }
+                }
+            }
+            Message::StaySignedIn => {
+                let now = Instant::now();
+                self.now = now;
+                if let Some(session) = self.session.as_mut() {
+                    session.extend(now);
+                    self.toast = Some(String::from("You're still signed in."));
+                }
+            }
+            Message::ModalityChanged(modality) => self.modality = modality,
+            Message::Logout => {
+                self.log_activity("logged out");
+                self.session = None;
+                self.page = Page::Login;
+                self.history.clear();
+                self.page_changed();
+            }
+            Message::MoveField(kind, offset) => {
+                self.settings.move_field(kind, offset);
+            }
+            Message::TextScaleChanged(scale) => {
+                self.settings.text_scale = settings::clamp_text_scale(scale);
+            }
+            Message::SaveSettings => {
+                self.save_settings();
+            }
+            Message::SettingsQueryChanged(query) => self.settings_query = query,
+            Message::DiscardChanges => {
+                self.discard_changes();
+                let decorations = self.sync_decorations();
+                if let Some(navigation) = self.pending_navigation.take() {
+                    return Command::batch([decorations, self.update(navigation)]);
+                }
+                return decorations;
+            }
+            Message::KeepEditing => self.pending_navigation = None,
+            Message::CopyDebugReport => {
+                self.toast = Some(String::from("Debug report copied to clipboard."));
+                return iced::clipboard::write(self.debug_report());
+            }
+            Message::CopyEmail => {
+                if let Some(session) = &self.session {
+                    self.toast = Some(String::from("Email copied to clipboard."));
+                    return iced::clipboard::write(session.email.clone());
                 }
             }
-            Message::LoginFieldChange(email, password) => {
-                self.login_field.email = email;
-                self.login_field.password = password;
+            Message::ReadOnlyEdited => {}
+            // leaving the settings page => changes are kept without asking, unless saving fails
+            Message::Router(_) | Message::Back if self.needs_autosave() => {
+                if self.autosave_settings() {
+                    return self.update(message);
+                }
+                self.pending_navigation = Some(message);
+            }
+            // unsaved edits => ask before leaving the page
+            Message::Router(_) | Message::Back if self.has_unsaved_changes() => {
+                self.pending_navigation = Some(message);
             }
-            Message::LoginSubmit => {}
             Message::Router(route) => {
-                if route == "Login" {
-                    self.page = Page::Login;
-                } else if route == "Register" {
-                    let _ = self.page == Page::Register;
+                if let Some(page) = Page::from_route(&route) {
+                    if page != self.page {
+                        let previous = std::mem::replace(&mut self.page, page);
+                        self.history.push(previous);
+                        self.page_changed();
+                        return Command::batch([self.read_clipboard(), self.focus_on_load()]);
+                    }
+                }
+            }
+            Message::Back => {
+                if let Some(page) = self.history.pop() {
+                    self.page = page;
+                    self.page_changed();
+                    return Command::batch([self.read_clipboard(), self.focus_on_load()]);
                 }
             }
         }
+        Command::none()
+    }
+
+    // refresh the home page summary while it is shown
+    fn subscription(&self) -> Subscription<Message> {
+        let modality = iced::event::listen_with(|event, _status| {
+            focus::modality_change(&event).map(Message::ModalityChanged)
+        });
+        // requests without known progress animate a spinner instead of a bar
+        let spinner = if self.busy && self.progress.is_none() {
+            timer::every(Duration::from_millis(300)).map(|_| Message::Spin)
+        } else {
+            Subscription::none()
+        };
+        // the last stretch of a session ticks every second => the banner counts down
+        let clock = match self.session.as_ref().filter(|_| self.page == Page::Home) {
+            Some(session) => {
+                let remaining = session.expires_at().saturating_duration_since(self.now);
+                let interval = if remaining <= session::EXPIRY_WARNING + Duration::from_secs(30) {
+                    Duration::from_secs(1)
+                } else {
+                    Duration::from_secs(30)
+                };
+                timer::every(interval).map(Message::Tick)
+            }
+            None => Subscription::none(),
+        };
+        let keys = iced::event::listen_with(key_pressed);
+        let window_events = iced::event::listen_with(|event, _status| match event {
+            iced::Event::Window(_, window::Event::Focused) => Some(Message::WindowFocused),
+            iced::Event::Window(_, window::Event::Unfocused) => Some(Message::WindowUnfocused),
+            iced::Event::Window(_, window::Event::Moved { x, y }) => {
+                Some(Message::WindowMoved(iced::Point::new(x as f32, y as f32)))
+            }
+            iced::Event::Window(_, window::Event::Resized { width, height }) => {
+                Some(Message::WindowResized(iced::Size::new(width as f32, height as f32)))
+            }
+            iced::Event::Window(_, window::Event::CloseRequested) => Some(Message::CloseWindow),
+            _ => None,
+        });
+        // demo mode => scripted steps on a timer, stopped by any real key press or click
+        let demo = if self.demo.is_some() {
+            Subscription::batch([
+                timer::every(demo::STEP_INTERVAL).map(|_| Message::DemoStep),
+                iced::event::listen_with(|event, _status| match event {
+                    iced::Event::Keyboard(iced::keyboard::Event::KeyPressed { .. })
+                    | iced::Event::Mouse(iced::mouse::Event::ButtonPressed(_)) => Some(Message::StopDemo),
+                    _ => None,
+                }),
+            ])
+        } else {
+            Subscription::none()
+        };
+        let reveal = if self.reveal.is_some() {
+            window::frames().map(Message::RevealTick)
+        } else {
+            Subscription::none()
+        };
+        let switch = if self.switch_animation.is_some() {
+            window::frames().map(Message::SwitchTick)
+        } else {
+            Subscription::none()
+        };
+        let success = if self.success.is_some() {
+            window::frames().map(Message::SuccessTick)
+        } else {
+            Subscription::none()
+        };
+        let labels = if self.labels_moving() {
+            window::frames().map(Message::LabelTick)
+        } else {
+            Subscription::none()
+        };
+        // every edit restarts the timer => it only fires once typing pauses
+        let settle = if self.unsettled.is_some() {
+            timer::after(self.edit_generation, Duration::from_millis(self.settings.debounce_ms.into()))
+                .map(Message::InputSettled)
+        } else {
+            Subscription::none()
+        };
+        Subscription::batch([modality, keys, window_events, demo, spinner, clock, settle, reveal, switch, labels, success])
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let content = match self.page {
-            Page::Login => log_in_page(&self.login_field),
-            Page::Register => register_page(),
+            Page::Login => log_in_page(self),
+            Page::Register => register_page(self),
+            Page::Settings => settings_page(
+                &self.settings,
+                self.has_unsaved_changes(),
+                &self.settings_query,
+                self.switch_animation.map(|(label, _, progress)| (label, progress)),
+            ),
+            Page::Home => home_page(self.session.as_ref(), self.now, self.settings.text_scale),
+            Page::Error => error_page(self.failure.as_ref(), self.settings.text_scale),
+        };
+
+        // held navigation => the confirm dialog takes the place of the page
+        let content = if self.pending_navigation.is_some() {
+            discard_dialog()
+        } else if let Some((_, progress, _)) = &self.success {
+            // reduce motion => the finished check from the start
+            let progress = if self.settings.reduce_motion { 1.0 } else { *progress };
+            container(illustrations::success_check(progress, self.settings.text_scale)).padding(Padding::from(20))
+        } else {
+            content
         };
 
         let wrapper = Column::new()
@@ -102,155 +1005,1656 @@ impl Sandbox for RustUI {
             .width(Length::Fill)
             .align_items(Alignment::Center)
             .push(content)
-            .push(
-                match self.page {
-                    Page::Login => page_footer(
-                        button("Page Two")
-                            .on_press(Message::Router("Register".to_string()))
-                            .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton)))
-                    ),
-                    Page::Register => page_footer(
-                        button("Main Page - Login")
-                            .on_press(Message::Router("Login".to_string()))
-                            .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton)))
-                    ),
-                }
-            );
+            .push_maybe(self.show_help.then(|| help_overlay(&self.page)))
+            .push_maybe(self.show_errors.then(|| errors_overlay(&self.error_log)))
+            .push_maybe(self.expiry_banner())
+            .push_maybe(self.toast.as_deref().map(toast))
+            .push_maybe(self.show_notifications.then(|| notifications_dropdown(&self.notifications)))
+            .push(page_footer(self.footer_buttons()));
 
-        container(wrapper)
+        let page = container(wrapper)
             .width(Length::Fill)
             .height(Length::Fill)
             .padding(Padding::from(20))
             .center_x()
-            .center_y()
-            .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
-            .into()
+            .align_y(self.settings.alignment.vertical())
+            .style(iced::theme::Container::Custom(Box::new(ContainerStyle)));
+
+        // borderless => the app draws the title bar the OS no longer provides
+        let view = Column::new()
+            .push_maybe((!self.decorated).then(|| title_strip(self.title())))
+            .push(page)
+            .into();
+
+        #[cfg(debug_assertions)]
+        self.latency.borrow_mut().view_produced();
+
+        view
     }
 }
 
-// page footer
-fn page_footer(btn: Button<Message>) -> Container<Message> {
-    let footer = Row::new().push(
-        button("Toggle Theme")
-            .on_press(Message::ToggleTheme)
-            .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton)),
+impl RustUI {
+    // app state from already loaded files => main() and new() read them from disk, tests pass their own
+    fn with_state(flags: cli::Flags, settings: AppSettings, presets: Vec<Preset>, geometry: WindowState) -> Self {
+        // deep link => start on the requested page with login underneath it in the history
+        let page = match flags.route.as_deref() {
+            Some(route) => Page::from_route(route).unwrap_or_else(|| {
+                eprintln!("warning: unknown route `{route}`, starting on the login page");
+                Page::Login
+            }),
+            None => Page::Login,
+        };
+        let history = if page == Page::Login { Vec::new() } else { vec![Page::Login] };
+        // main() already applied the setting to the window
+        let decorated = !settings.borderless;
+
+        Self {
+            theme: Theme::Dark,
+            page,
+            history,
+            login_field: LoginField {
+                email: String::new(),
+                password: String::new(),
+            },
+            register_field: RegisterField::default(),
+            settings: settings.clone(),
+            saved_settings: settings,
+            pending_navigation: None,
+            settings_query: String::new(),
+            show_help: false,
+            show_errors: false,
+            notifications: Notifications::default(),
+            show_notifications: false,
+            presets,
+            demo: flags.demo.then_some(0),
+            hovered_link: None,
+            decorated,
+            geometry,
+            email_domains: combo_box::State::new(
+                form::COMMON_DOMAINS.iter().map(|domain| domain.to_string()).collect(),
             ),
-    )
-        .push(btn)
-        .align_items(Alignment::Center)
-        .spacing(10);
+            clipboard_email: None,
+            paste_dismissed: false,
+            password_visible: false,
+            reveal: None,
+            switch_animation: None,
+            label_float: HashMap::new(),
+            label_tick: None,
+            success: None,
+            preset_name: String::new(),
+            #[cfg(debug_assertions)]
+            latency: Default::default(),
+            errors: Vec::new(),
+            focused: None,
+            autofocused: false,
+            edit_generation: 0,
+            unsettled: None,
+            modality: Modality::Mouse,
+            busy: false,
+            progress: None,
+            spinner_frame: 0,
+            toast: None,
+            session: None,
+            now: Instant::now(),
+            activity: Vec::new(),
+            email_checks: TtlCache::new(EMAIL_CHECK_CAPACITY, EMAIL_CHECK_TTL),
+            email_check: None,
+            error_log: ErrorLog::new(ERROR_LOG_CAPACITY),
+            in_flight: None,
+            failure: None,
+        }
+    }
 
-    container(footer).center_x().center_y()
-}
+    // run an async request => login and register mark the app busy until they answer
+    fn start(&mut self, operation: Operation) -> Command<Message> {
+        let timeout = self.settings.request_timeout();
+        match operation.clone() {
+            Operation::Login { email, password } => {
+                self.busy = true;
+                self.in_flight = Some(operation);
+                Command::perform(api::login(email, password, timeout), Message::LoginResult)
+            }
+            Operation::Register { email, password } => {
+                self.busy = true;
+                self.progress = Some(0.0);
+                self.in_flight = Some(operation);
+                Command::run(api::register(email, password, timeout), Message::Register)
+            }
+            Operation::CheckEmail(email) => {
+                self.email_check = Some((email.clone(), None));
+                Command::perform(api::check_email(email.clone(), timeout), move |result| {
+                    Message::EmailChecked(email, result)
+                })
+            }
+        }
+    }
 
+    // every failed request ends up here => expected errors become a toast,
+    // anything else routes to the error page so it can be retried
+    fn request_failed(&mut self, error: ApiError, operation: Option<Operation>) {
+        self.error_log.push(ErrorSource::Request, error.to_string());
+        match operation.filter(|_| !error.is_expected()) {
+            Some(operation) => {
+                self.failure = Some(Failure { error, operation });
+                if self.page != Page::Error {
+                    let previous = std::mem::replace(&mut self.page, Page::Error);
+                    self.history.push(previous);
+                    self.page_changed();
+                }
+            }
+            None => self.toast = Some(error.to_string()),
+        }
+    }
 
-// login page
-fn log_in_page(login_field: &LoginField) -> Container<Message> {
-    let column = Column::new()
-        .push(text("Graphical User Interface - Iced!"))
-        .push(
-            input_field("Email Address... ", &login_field.email)
-                .on_input(
-                    |email| {
-                        Message::LoginFieldChange(email, login_field.password.clone())
-                    }
-                )
-        )
-        .push(
-            input_field("Password... ", &login_field.password)
-                .on_input(
-                    |password| {
-                        Message::LoginFieldChange(login_field.email.clone(), password)
-                    }
-                )
-        )
-        .push(submit_btn("Login", Message::LoginSubmit))
-        .padding(Padding::from([50, 20]))
-        .align_items(Alignment::Center)
-        .spacing(40);
+    // autofocus after opening a page => the field picked in the settings, if the page shows it
+    fn focus_on_load(&mut self) -> Command<Message> {
+        match self.settings.initial_focus.target(&self.visible_fields()) {
+            Some(kind) => {
+                self.focused = Some(kind);
+                self.autofocused = true;
+                text_input::focus(kind.input_id())
+            }
+            None => Command::none(),
+        }
+    }
 
-    container(column)
-        .padding(Padding::from(20))
-        .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
-}
+    // reset per-page state after navigating
+    fn page_changed(&mut self) {
+        self.errors.clear();
+        self.focused = None;
+        self.autofocused = false;
+        self.unsettled = None;
+        self.password_visible = false;
+        self.reveal = None;
+        // a link that disappears under the mouse never sees it leave
+        self.hovered_link = None;
+        self.log_activity(&format!("navigated to {:?}", self.page));
+    }
 
+    // navigation buttons shown next to the theme toggle
+    // => navigation is rendered as links, logging out stays a button
+    fn footer_buttons(&self) -> Vec<Element<'_, Message>> {
+        let mut buttons = vec![match self.page {
+            Page::Login => self.link("Page Two", Message::Router("Register".to_string())),
+            Page::Home => button("Logout")
+                .on_press(Message::Logout)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton)))
+                .into(),
+            Page::Register | Page::Settings | Page::Error => {
+                self.link("Main Page - Login", Message::Router("Login".to_string()))
+            }
+        }];
+        if matches!(self.page, Page::Login | Page::Register) {
+            buttons.push(self.link("Settings", Message::Router("Settings".to_string())));
+        }
+        if !self.history.is_empty() {
+            buttons.push(self.link("Back", Message::Back));
+        }
+        buttons.push(bell(self.notifications.unread_count()));
+        buttons
+    }
 
-// register page
-fn register_page() -> Container<'static, Message> {
-    let column = Column::new().push(text("Page Two").size(64));
-    container(column)
-        .height(Length::Fill)
-        .width(Length::Fill)
-        .center_y()
-        .center_x()
-}
+    // link styled action => accent text, underlined only while hovered
+    fn link(&self, label: &'static str, message: Message) -> Element<'_, Message> {
+        let underline = container(Space::with_height(Length::Fixed(1.0)))
+            .width(Length::Fill)
+            .height(Length::Fixed(1.0))
+            .style(iced::theme::Container::Custom(Box::new(UnderlineStyle {
+                visible: self.hovered_link == Some(label),
+            })));
+        // text first => the underline takes the width of the label
+        let content = Column::new().push(text(label)).push(underline).spacing(1);
 
+        mouse_area(
+            button(content)
+                .on_press(message)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Link))),
+        )
+        .on_enter(Message::LinkHovered(Some(label)))
+        .on_exit(Message::LinkHovered(None))
+        .into()
+    }
 
-// input field
-fn input_field(_placeholder: &str, _value: &str) -> TextInput<'static, Message> {
-    TextInput::new(_placeholder, _value)
-        .width(Length::Fixed(500.0))
+    // single line login => only while the window is wide enough to fit it
+    fn login_bar(&self) -> bool {
+        self.page == Page::Login
+            && self.settings.login_layout == LoginLayout::Bar
+            && self.geometry.size.width >= LOGIN_BAR_MIN_WIDTH * self.settings.text_scale
+    }
+
+    // width of a form input => narrower when the login form shares one line
+    fn field_width(&self) -> f32 {
+        let width = if self.login_bar() { LOGIN_BAR_FIELD_WIDTH } else { FIELD_WIDTH };
+        width * self.settings.text_scale
+    }
+
+    // where a field's label belongs => above the field while focused or filled, else inside as placeholder
+    fn label_target(&self, kind: FieldKind) -> f32 {
+        if self.focused == Some(kind) || !self.field_value(kind).is_empty() {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    // current label position => reduce motion snaps straight to the target
+    fn label_position(&self, kind: FieldKind) -> f32 {
+        if self.settings.reduce_motion {
+            self.label_target(kind)
+        } else {
+            self.label_float.get(&kind).copied().unwrap_or(0.0)
+        }
+    }
+
+    fn labels_moving(&self) -> bool {
+        self.visible_fields()
+            .into_iter()
+            .any(|kind| self.label_position(kind) != self.label_target(kind))
+    }
+
+    // fields of the current page in the configured order
+    fn visible_fields(&self) -> Vec<FieldKind> {
+        form::ordered_fields(&self.settings.field_order, self.page.form_fields())
+    }
+
+    // current value of a field on the current page
+    fn field_value(&self, kind: FieldKind) -> &str {
+        match (&self.page, kind) {
+            (Page::Register, FieldKind::Username) => &self.register_field.username,
+            (Page::Register, FieldKind::Email) => &self.register_field.email,
+            (Page::Register, FieldKind::Password) => &self.register_field.password,
+            (Page::Register, FieldKind::ConfirmPassword) => &self.register_field.confirm_password,
+            (Page::Register, FieldKind::Phone) => &self.register_field.phone,
+            (_, FieldKind::Email) => &self.login_field.email,
+            (_, FieldKind::Password) => &self.login_field.password,
+            (_, FieldKind::Username | FieldKind::ConfirmPassword | FieldKind::Phone) => "",
+        }
+    }
+
+    // typing in a field means it holds focus => re-check it while an error is still shown
+    fn field_edited(&mut self, kind: FieldKind) -> Command<Message> {
+        let command = self.move_focus(Some(kind));
+        // debounced => the value is already stored, the checks wait until typing pauses
+        if self.settings.debounce_ms > 0 {
+            self.edit_generation += 1;
+            self.unsettled = Some(kind);
+        } else {
+            self.live_validate(kind);
+        }
+        command
+    }
+
+    // checks run while typing
+    fn live_validate(&mut self, kind: FieldKind) {
+        // a mismatch only shows up after leaving the confirmation => edits only ever clear it
+        let confirmed = form::validate_in_form(FieldKind::ConfirmPassword, |k| self.field_value(k)).is_ok();
+        // typing into a confirmation that was left empty => asking for it no longer applies
+        let started = kind == FieldKind::ConfirmPassword
+            && !self.field_value(kind).is_empty()
+            && self.field_error(kind) == Some(form::CONFIRM_REQUIRED);
+        if matches!(kind, FieldKind::Password | FieldKind::ConfirmPassword) && (confirmed || started) {
+            self.errors.retain(|(k, _)| *k != FieldKind::ConfirmPassword);
+        }
+        if kind != FieldKind::ConfirmPassword && self.field_error(kind).is_some() {
+            self.revalidate(kind);
+        }
+    }
+
+    // value that wasn't typed (a hand-editable preset) => cleaned up the same way typed input is
+    fn fill_field(&mut self, kind: FieldKind, value: &str) {
+        let (value, _) = form::cap_length(form::strip_tabs(value.to_string()));
+        let value = match kind {
+            FieldKind::Phone => form::phone_digits(&value),
+            FieldKind::Email if self.settings.lowercase_email_domain => form::normalize_email(&value),
+            _ => value,
+        };
+        if let Some(field) = self.field_value_mut(kind) {
+            *field = value;
+        }
+    }
+
+    // track a focus transition => the field that lost focus gets validated
+    fn move_focus(&mut self, next: Option<FieldKind>) -> Command<Message> {
+        // leaving a field settles it right away => a pending timer must not run it again
+        if self.unsettled.is_some_and(|kind| Some(kind) != next) {
+            self.unsettled = None;
+        }
+        // nothing typed into the autofocused field yet => it wasn't visited, so there's nothing to check
+        let blurred = form::blurred_field(self.focused, next).filter(|_| !self.autofocused);
+        self.focused = next;
+        self.autofocused = false;
+        match blurred {
+            Some(blurred) => {
+                self.revalidate(blurred);
+                if let Some(error) = self.field_error(blurred) {
+                    let entry = format!("{}: {error}", blurred.label());
+                    self.error_log.push(ErrorSource::Validation, entry);
+                }
+                if blurred == FieldKind::Email {
+                    return self.check_email_availability();
+                }
+                Command::none()
+            }
+            None => Command::none(),
+        }
+    }
+
+    // ask whether the register email is free => answers are cached per exact value
+    fn check_email_availability(&mut self) -> Command<Message> {
+        let email = self.register_field.email.trim().to_string();
+        if self.page != Page::Register || !form::is_valid_email(&email) {
+            return Command::none();
+        }
+        if let Some(check) = self.email_checks.get(&email, Instant::now()) {
+            self.email_check = Some((email, Some(Ok(check))));
+            return Command::none();
+        }
+        self.start(Operation::CheckEmail(email))
+    }
+
+    // availability check of the register email, if it is about the current value => inner None while waiting
+    fn email_availability(&self) -> Option<Option<&Result<CheckResult, ApiError>>> {
+        match &self.email_check {
+            Some((email, check)) if self.page == Page::Register && *email == self.register_field.email.trim() => {
+                Some(check.as_ref())
+            }
+            _ => None,
+        }
+    }
+
+    // replace the error of a single field, keeping errors in field order
+    fn revalidate(&mut self, kind: FieldKind) {
+        let result = form::validate_in_form(kind, |k| self.field_value(k));
+        self.errors.retain(|(k, _)| *k != kind);
+        if let Err(error) = result {
+            self.errors.push((kind, error));
+        }
+        let fields = self.visible_fields();
+        self.errors
+            .sort_by_key(|(k, _)| fields.iter().position(|field| field == k));
+    }
+
+    // enforce the input length limit and drop tab characters on every text input message
+    fn cap_input(&mut self, message: Message) -> Message {
+        let (value, rebuild): (String, fn(String) -> Message) = match message {
+            Message::UsernameChanged(value) => (value, Message::UsernameChanged),
+            Message::EmailChanged(value) => (value, Message::EmailChanged),
+            Message::PasswordChanged(value) => (value, Message::PasswordChanged),
+            Message::ConfirmPasswordChanged(value) => (value, Message::ConfirmPasswordChanged),
+            Message::PhoneChanged(value) => (value, Message::PhoneChanged),
+            Message::PresetNameChanged(value) => (value, Message::PresetNameChanged),
+            Message::SettingsQueryChanged(value) => (value, Message::SettingsQueryChanged),
+            other => return other,
+        };
+        let (value, truncated) = form::cap_length(form::strip_tabs(value));
+        if truncated {
+            self.toast = Some(String::from("Input too long, truncated."));
+        }
+        rebuild(value)
+    }
+
+    // every field of the form has a value => the submit button is enabled
+    fn form_filled(&self) -> bool {
+        self.visible_fields()
+            .into_iter()
+            .all(|kind| kind.is_optional() || !self.field_value(kind).trim().is_empty())
+    }
+
+    // session about to end => a non-blocking banner that keeps it alive when clicked
+    fn expiry_banner(&self) -> Option<Element<'_, Message>> {
+        let session = self.session.as_ref().filter(|session| session.warned)?;
+        let seconds = session.expires_at().saturating_duration_since(self.now).as_secs();
+        Some(
+            button(text(format!("Session expiring in {seconds}s \u{2014} click to stay signed in")))
+                .on_press(Message::StaySignedIn)
+                .padding(Padding::from([10, 20]))
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Standard)))
+                .into(),
+        )
+    }
+
+    // look for an email to offer in the paste chip => only while it could still be shown
+    fn read_clipboard(&self) -> Command<Message> {
+        if self.paste_dismissed || !self.visible_fields().contains(&FieldKind::Email) {
+            return Command::none();
+        }
+        iced::clipboard::read(Message::ClipboardRead)
+    }
+
+    // clipboard email to offer above the email field => only while the field is empty
+    fn paste_suggestion(&self) -> Option<&str> {
+        self.clipboard_email
+            .as_deref()
+            .filter(|_| !self.paste_dismissed && self.field_value(FieldKind::Email).is_empty())
+    }
+
+    // bring the window decorations in line with the borderless setting
+    fn sync_decorations(&mut self) -> Command<Message> {
+        if self.decorated != self.settings.borderless {
+            return Command::none();
+        }
+        self.decorated = !self.settings.borderless;
+        window::toggle_decorations(window::Id::MAIN)
+    }
+
+    // persist the settings => failures are reported but never fatal, true once saved
+    fn save_settings(&mut self) -> bool {
+        match self.settings.save() {
+            Ok(()) => {
+                self.saved_settings = self.settings.clone();
+                self.notifications.push("Settings saved.");
+                self.toast = Some(String::from("Settings saved."));
+                true
+            }
+            Err(error) => {
+                eprintln!("failed to save settings: {error}");
+                self.error_log.push(ErrorSource::Storage, format!("failed to save settings: {error}"));
+                false
+            }
+        }
+    }
+
+    // settings are saved on leave => only written when something changed
+    fn autosave_settings(&mut self) -> bool {
+        if !self.needs_autosave() {
+            return true;
+        }
+        self.save_settings()
+    }
+
+    // changed settings still on screen => leaving the page writes them
+    fn needs_autosave(&self) -> bool {
+        self.page == Page::Settings && self.has_unsaved_changes()
+    }
+
+    // edits on the current page that would be lost by navigating away
+    fn has_unsaved_changes(&self) -> bool {
+        match self.page {
+            Page::Settings => self.settings != self.saved_settings,
+            Page::Register => self.register_field != RegisterField::default(),
+            Page::Login | Page::Home | Page::Error => false,
+        }
+    }
+
+    // restore the current page to its baseline
+    fn discard_changes(&mut self) {
+        match self.page {
+            Page::Settings => self.settings = self.saved_settings.clone(),
+            Page::Register => self.register_field = RegisterField::default(),
+            Page::Login | Page::Home | Page::Error => {}
+        }
+        self.errors.clear();
+    }
+
+    // remember a notable event for the debug report => oldest entries drop off
+    fn log_activity(&mut self, entry: &str) {
+        self.activity.push(entry.to_string());
+        if self.activity.len() > ACTIVITY_LIMIT {
+            self.activity.remove(0);
+        }
+    }
+
+    // plain text report for bug filing => passwords never appear in it
+    fn debug_report(&self) -> String {
+        let activity = if self.activity.is_empty() {
+            String::from("  (none)")
+        } else {
+            self.activity
+                .iter()
+                .map(|entry| format!("  - {entry}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        format!(
+            "Rust UI - Iced debug report\n\
+             version: {}\n\
+             os: {} ({})\n\
+             page: {:?}\n\
+             theme: {:?}\n\
+             settings:\n  {}\n\
+             login email: {}\n\
+             login password: {}\n\
+             register password: {}\n\
+             signed in: {}\n\
+             recent activity:\n{}\n\
+             last error: {}\n",
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            self.page,
+            self.theme,
+            self.settings.serialize().trim_end().replace('\n', "\n  "),
+            self.login_field.email,
+            redact(&self.login_field.password),
+            redact(&self.register_field.password),
+            self.session.is_some(),
+            activity,
+            self.error_log.latest().map_or("(none)", |entry| entry.message.as_str()),
+        )
+    }
+
+    // editable value of a field on the current page
+    fn field_value_mut(&mut self, kind: FieldKind) -> Option<&mut String> {
+        match (&self.page, kind) {
+            (Page::Register, FieldKind::Username) => Some(&mut self.register_field.username),
+            (Page::Register, FieldKind::Email) => Some(&mut self.register_field.email),
+            (Page::Register, FieldKind::Password) => Some(&mut self.register_field.password),
+            (Page::Register, FieldKind::ConfirmPassword) => Some(&mut self.register_field.confirm_password),
+            (Page::Register, FieldKind::Phone) => Some(&mut self.register_field.phone),
+            (Page::Login, FieldKind::Email) => Some(&mut self.login_field.email),
+            (Page::Login, FieldKind::Password) => Some(&mut self.login_field.password),
+            _ => None,
+        }
+    }
+
+    // error for a field from the last validation, if any
+    fn field_error(&self, kind: FieldKind) -> Option<&str> {
+        self.errors
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, error)| error.as_str())
+    }
+}
+
+// key presses => the registry is consulted in update, where the current page is known
+fn key_pressed(event: iced::Event, status: iced::event::Status) -> Option<Message> {
+    let iced::Event::Keyboard(iced::keyboard::Event::KeyPressed { key, modifiers, .. }) = event else {
+        return None;
+    };
+    Some(Message::KeyPressed(key, modifiers, status == iced::event::Status::Captured))
+}
+
+// message a shortcut runs
+fn shortcut_message(action: shortcuts::Action) -> Message {
+    match action {
+        shortcuts::Action::Submit => Message::EnterPressed,
+        shortcuts::Action::FocusEmail => Message::FocusEmail,
+        shortcuts::Action::ToggleHelp => Message::ToggleHelp,
+        shortcuts::Action::ToggleErrors => Message::ToggleErrors,
+        shortcuts::Action::Dismiss => Message::Dismiss,
+        shortcuts::Action::FocusNext => Message::CycleFocus(1),
+        shortcuts::Action::FocusPrevious => Message::CycleFocus(-1),
+    }
+}
+
+// hide secret values => only says whether something was entered
+fn redact(secret: &str) -> &'static str {
+    if secret.is_empty() {
+        "(empty)"
+    } else {
+        "(redacted)"
+    }
+}
+
+// page footer
+fn page_footer(buttons: Vec<Element<Message>>) -> Container<Message> {
+    let footer = Row::new().push(
+        button("Toggle Theme")
+            .on_press(Message::ToggleTheme)
+            .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton)),
+            ),
+    )
+        .extend(buttons)
+        .align_items(Alignment::Center)
+        .spacing(10);
+
+    container(footer).center_x().center_y()
+}
+
+
+// custom title bar for borderless mode => drag anywhere on it, buttons on the right
+fn title_strip(title: String) -> Element<'static, Message> {
+    let window_button = |label, message| {
+        button(text(label).width(Length::Fixed(16.0)).horizontal_alignment(Horizontal::Center))
+            .on_press(message)
+            .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Link)))
+    };
+    let strip = Row::new()
+        .push(text(title).size(14))
+        .push(Space::with_width(Length::Fill))
+        .push(window_button("_", Message::MinimizeWindow))
+        .push(window_button("x", Message::CloseWindow))
+        .align_items(Alignment::Center)
+        .spacing(4)
+        .padding(Padding::from([2, 10]));
+
+    // the buttons capture their own presses => pressing them never starts a drag
+    mouse_area(
+        container(strip)
+            .width(Length::Fill)
+            .style(iced::theme::Container::Custom(Box::new(ContainerStyle))),
+    )
+    .on_press(Message::DragWindow)
+    .on_right_press(Message::WindowMenu)
+    .into()
+}
+
+
+// on / off setting => a track with a sliding knob, the label names the switch for its animation
+fn toggle_switch(
+    value: bool,
+    on_toggle: fn(bool) -> Message,
+    label: &'static str,
+    animation: Option<(&'static str, f32)>,
+) -> Row<'static, Message> {
+    // 0.0 => off, 1.0 => on, in between while the knob slides toward `value`
+    let position = match animation {
+        Some((animating, progress)) if animating == label => {
+            if value {
+                progress
+            } else {
+                1.0 - progress
+            }
+        }
+        _ => f32::from(u8::from(value)),
+    };
+    let knob = container(Space::new(Length::Fixed(KNOB_SIZE), Length::Fixed(KNOB_SIZE)))
+        .style(iced::theme::Container::Custom(Box::new(KnobStyle)));
+    let track = container(
+        Row::new()
+            .push(Space::with_width(Length::Fixed(position * (TRACK_WIDTH - KNOB_SIZE - 4.0))))
+            .push(knob),
+    )
+    .width(Length::Fixed(TRACK_WIDTH))
+    .padding(Padding::from(2))
+    .style(iced::theme::Container::Custom(Box::new(TrackStyle { position })));
+
+    Row::new()
+        .push(
+            button(track)
+                .on_press(Message::Switch(label, Box::new(on_toggle(!value))))
+                .padding(Padding::from(0))
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Link))),
+        )
+        .push(text(label))
+        .align_items(Alignment::Center)
+        .spacing(10)
+}
+
+// toggle switch dimensions
+const TRACK_WIDTH: f32 = 40.0;
+const KNOB_SIZE: f32 = 18.0;
+
+
+// horizontal rule with a centered label => the two lines share whatever width is left
+fn labeled_divider(label: &str) -> Row<'_, Message> {
+    let line = || {
+        container(Space::with_height(Length::Fixed(1.0)))
+            .width(Length::Fill)
+            .height(Length::Fixed(1.0))
+            .style(iced::theme::Container::Custom(Box::new(DividerStyle { line: true })))
+    };
+    Row::new()
+        .push(line())
+        .push(
+            container(text(label).size(14))
+                .style(iced::theme::Container::Custom(Box::new(DividerStyle { line: false }))),
+        )
+        .push(line())
+        .align_items(Alignment::Center)
+        .spacing(10)
+        .width(Length::Fill)
+}
+
+// exclusive choice => one accent styled radio per option
+fn radio_group<V>(options: &[V], selected: V, on_select: fn(V) -> Message) -> Column<'static, Message>
+where
+    V: Copy + Eq + std::fmt::Display,
+{
+    options.iter().fold(Column::new().spacing(8), |column, option| {
+        column.push(
+            radio(option.to_string(), *option, Some(selected), on_select)
+                .size(18)
+                .spacing(10)
+                .style(iced::theme::Radio::Custom(Box::new(RadioStyle))),
+        )
+    })
+}
+
+
+// multi-step flow => numbered circles joined by lines, colored by step state
+fn stepper(current: usize, labels: &[&str], scale: f32) -> Row<'static, Message> {
+    let mut row = Row::new().align_items(Alignment::Start);
+    for (index, label) in labels.iter().enumerate() {
+        let state = steps::step_state(index, current);
+        if index > 0 {
+            // the line leading into a step is lit once that step is reached
+            let line = container(Space::with_height(Length::Fixed(2.0)))
+                .width(Length::Fixed(60.0 * scale))
+                .height(Length::Fixed(2.0))
+                .style(iced::theme::Container::Custom(Box::new(StepLineStyle {
+                    lit: state != StepState::Upcoming,
+                })));
+            row = row.push(
+                Column::new()
+                    .push(Space::with_height(Length::Fixed(13.0 * scale)))
+                    .push(line),
+            );
+        }
+        let number = container(text(index + 1).size(14.0 * scale))
+        .width(Length::Fixed(28.0 * scale))
+        .height(Length::Fixed(28.0 * scale))
+        .center_x()
+        .center_y()
+        .style(iced::theme::Container::Custom(Box::new(StepStyle { state, scale })));
+        row = row.push(
+            Column::new()
+                .push(number)
+                .push(text(*label).size(12.0 * scale))
+                .align_items(Alignment::Center)
+                .spacing(6)
+                .width(Length::Fixed(100.0 * scale)),
+        );
+    }
+    row
+}
+
+
+// page and section titles => bold text over a short accent bar, sized with the text scale
+fn section_heading(title: &str, scale: f32) -> Column<'static, Message> {
+    let bold = iced::Font {
+        weight: iced::font::Weight::Bold,
+        ..iced::Font::DEFAULT
+    };
+    let bar = container(Space::with_height(Length::Fixed(3.0 * scale)))
+        .width(Length::Fixed(48.0 * scale))
+        .height(Length::Fixed(3.0 * scale))
+        .style(iced::theme::Container::Custom(Box::new(UnderlineStyle { visible: true })));
+
+    Column::new()
+        .push(text(title).size(40.0 * scale).font(bold))
+        .push(bar)
+        .align_items(Alignment::Center)
+        .spacing(6.0 * scale)
+}
+
+
+// login page
+fn log_in_page(app: &RustUI) -> Container<'_, Message> {
+    let submit = submit_btn(
+        &if app.busy {
+            format!("Logging in{}", SPINNER_FRAMES[app.spinner_frame])
+        } else {
+            String::from("Login")
+        },
+        app.form_filled().then_some(Message::LoginSubmit),
+        &app.settings,
+    );
+
+    let column = Column::new().push(text("Graphical User Interface - Iced!"));
+    let column = if app.login_bar() {
+        // fields and button side by side => same inputs and messages as the stacked form
+        let bar = app
+            .visible_fields()
+            .into_iter()
+            .fold(Row::new().spacing(20), |row, kind| row.push(form_field(app, kind)))
+            .push(submit.width(Length::Fixed(140.0 * app.settings.text_scale)));
+        column.push(bar).push(preset_bar(app))
+    } else {
+        column.push(form_fields(app)).push(preset_bar(app)).push(submit)
+    };
+    let column = column
+        .push(labeled_divider("or").width(Length::Fixed(app.field_width())))
+        .push(
+            button("Create an account")
+                .on_press(Message::Router("Register".to_string()))
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton))),
+        )
+        .padding(Padding::from([50, 20]))
+        .align_items(Alignment::Center)
+        .spacing(40);
+
+    container(column)
+        .padding(Padding::from(20))
+        .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
+}
+
+
+// register page
+fn register_page(app: &RustUI) -> Container<'_, Message> {
+    let column = Column::new()
+        .push(section_heading("Page Two", app.settings.text_scale))
+        .push(form_fields(app))
+        .push(preset_bar(app))
+        .push(submit_btn(
+            "Create Account",
+            app.form_filled().then_some(Message::RegisterSubmit),
+            &app.settings,
+        ))
+        .push_maybe(app.progress.map(|progress| {
+            progress_bar(0.0..=1.0, progress)
+                .width(Length::Fixed(FIELD_WIDTH * app.settings.text_scale))
+                .height(Length::Fixed(8.0))
+                .style(iced::theme::ProgressBar::Custom(Box::new(ProgressStyle)))
+        }))
+        .push_maybe(app.progress.map(|progress| {
+            let labels = &api::REGISTER_STEP_LABELS;
+            let current = (progress * labels.len() as f32).round() as usize;
+            stepper(current, labels, app.settings.text_scale)
+        }))
+        .align_items(Alignment::Center)
+        .spacing(40);
+    container(column)
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_y(app.settings.alignment.vertical())
+        .center_x()
+}
+
+
+// home page => shown once signed in
+fn home_page(session: Option<&Session>, now: Instant, scale: f32) -> Container<'_, Message> {
+    let mut column = Column::new()
+        .push(section_heading("Welcome!", scale))
+        .align_items(Alignment::Center)
+        .spacing(20);
+
+    // summary card => who signed in, when, and when the session ends
+    if let Some(session) = session {
+        column = column.push(avatar(&session.email));
+        // read-only input => the email can be focused and selected, edits are dropped
+        let email = Row::new()
+            .push(text("Signed in as"))
+            .push(
+                text_input("", &session.email)
+                    .on_input(|_| Message::ReadOnlyEdited)
+                    .width(Length::Fixed(260.0 * scale)),
+            )
+            .push(
+                button("Copy")
+                    .on_press(Message::CopyEmail)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Standard))),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+        let summary = Column::new()
+            .push(email)
+            .push(text(format!(
+                "Logged in {}",
+                session::time_ago(now.saturating_duration_since(session.logged_in_at))
+            )))
+            .push(text(format!(
+                "Session expires {}",
+                session::time_until(session.expires_at().saturating_duration_since(now))
+            )))
+            .spacing(10);
+        column = column.push(
+            container(summary)
+                .padding(Padding::from(20))
+                .style(iced::theme::Container::Custom(Box::new(ContainerStyle))),
+        );
+    }
+
+    container(column)
+        .padding(Padding::from(20))
+        .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
+}
+
+
+// error page => an unexpected request failure with a way back and a retry
+fn error_page(failure: Option<&Failure>, scale: f32) -> Container<'_, Message> {
+    let message = failure.map_or_else(|| String::from("Unknown error."), |failure| failure.error.to_string());
+    let column = Column::new()
+        .push(illustrations::error_illustration(scale))
+        .push(text("Something went wrong").size(48))
+        .push(text(message))
+        .push(
+            Row::new()
+                .push(
+                    button("Go back")
+                        .on_press(Message::Back)
+                        .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton))),
+                )
+                .push(
+                    button("Retry")
+                        .on_press_maybe(failure.map(|_| Message::Retry))
+                        .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Standard))),
+                )
+                .spacing(10),
+        )
+        .align_items(Alignment::Center)
+        .spacing(20);
+
+    container(column)
+        .padding(Padding::from(20))
+        .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
+}
+
+
+// avatar placeholder => initials on an accent circle
+fn avatar(email: &str) -> Container<'static, Message> {
+    container(text(session::initials(email)).size(28).style(iced::Color::WHITE))
+        .width(Length::Fixed(72.0))
+        .height(Length::Fixed(72.0))
+        .center_x()
+        .center_y()
+        .style(iced::theme::Container::Custom(Box::new(AvatarStyle)))
+}
+
+
+// settings page
+fn settings_page<'a>(
+    settings: &'a AppSettings,
+    unsaved: bool,
+    query: &str,
+    switch_animation: Option<(&'static str, f32)>,
+) -> Container<'a, Message> {
+    let mut order = Column::new().spacing(10);
+    for (index, kind) in settings.field_order.iter().enumerate() {
+        let mut up = button("Up").style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton)));
+        let mut down = button("Down").style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton)));
+        if index > 0 {
+            up = up.on_press(Message::MoveField(*kind, -1));
+        }
+        if index + 1 < settings.field_order.len() {
+            down = down.on_press(Message::MoveField(*kind, 1));
+        }
+        order = order.push(
+            Row::new()
+                .push(text(kind.label()).width(Length::Fixed(200.0)))
+                .push(up)
+                .push(down)
+                .align_items(Alignment::Center)
+                .spacing(10),
+        );
+    }
+
+    let scale = Row::new()
+        .push(text(format!("{:.2}x", settings.text_scale)).width(Length::Fixed(60.0)))
+        .push(
+            slider(
+                settings::MIN_TEXT_SCALE..=settings::MAX_TEXT_SCALE,
+                settings.text_scale,
+                Message::TextScaleChanged,
+            )
+            .step(0.05)
+            .width(Length::Fixed(200.0)),
+        )
+        .align_items(Alignment::Center)
+        .spacing(10);
+
+    let debounce = Row::new()
+        .push(
+            text(match settings.debounce_ms {
+                0 => String::from("Off"),
+                ms => format!("{ms} ms"),
+            })
+            .width(Length::Fixed(60.0)),
+        )
+        .push(
+            slider(0..=settings::MAX_DEBOUNCE_MS, settings.debounce_ms, Message::DebounceChanged)
+                .step(50u32)
+                .width(Length::Fixed(200.0)),
+        )
+        .align_items(Alignment::Center)
+        .spacing(10);
+
+    let timeout = Row::new()
+        .push(text(format!("{} s", settings.request_timeout_secs)).width(Length::Fixed(60.0)))
+        .push(
+            slider(
+                settings::MIN_REQUEST_TIMEOUT_SECS..=settings::MAX_REQUEST_TIMEOUT_SECS,
+                settings.request_timeout_secs,
+                Message::RequestTimeoutChanged,
+            )
+            .width(Length::Fixed(200.0)),
+        )
+        .align_items(Alignment::Center)
+        .spacing(10);
+
+    // every setting with the label the search box filters on
+    let sections: Vec<(&str, Element<'a, Message>)> = vec![
+        ("Field order", order.into()),
+        ("Text scale", scale.into()),
+        ("Wait before validating while typing", debounce.into()),
+        ("Server request timeout", timeout.into()),
+        (
+            "Enter anywhere runs the page's main action",
+            toggle_switch(
+                settings.enter_submits,
+                Message::EnterSubmitsToggled,
+                "Submit from any field",
+                switch_animation,
+            )
+            .into(),
+        ),
+        (
+            "Clear the password after a failed login",
+            checkbox("Turn off to keep it for another try", settings.clear_password_on_failure)
+                .on_toggle(Message::ClearPasswordToggled)
+                .into(),
+        ),
+        (
+            "Lowercase email domains while typing",
+            checkbox("Turn off for mixed-case domains", settings.lowercase_email_domain)
+                .on_toggle(Message::LowercaseDomainToggled)
+                .into(),
+        ),
+        (
+            "Borderless window with a custom title bar",
+            checkbox("", settings.borderless)
+                .on_toggle(Message::BorderlessToggled)
+                .into(),
+        ),
+        (
+            "Page content alignment",
+            radio_group(&ContentAlignment::ALL, settings.alignment, Message::AlignmentSelected).into(),
+        ),
+        (
+            "Focus when a page opens",
+            radio_group(&InitialFocus::ALL, settings.initial_focus, Message::InitialFocusSelected).into(),
+        ),
+        (
+            "Login form layout",
+            radio_group(&LoginLayout::ALL, settings.login_layout, Message::LoginLayoutSelected).into(),
+        ),
+        (
+            "Disabled submit button look",
+            radio_group(&DisabledLook::ALL, settings.disabled_look, Message::DisabledLookSelected).into(),
+        ),
+        (
+            "Reduce motion",
+            toggle_switch(
+                settings.reduce_motion,
+                Message::ReduceMotionToggled,
+                "Skip decorative animations",
+                switch_animation,
+            )
+            .into(),
+        ),
+        (
+            "Copy debug report",
+            button("Copy")
+                .on_press(Message::CopyDebugReport)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton)))
+                .into(),
+        ),
+    ];
+
+    let matching: Vec<Element<'a, Message>> = sections
+        .into_iter()
+        .filter(|(label, _)| search::matches(label, query))
+        .map(|(label, control)| {
+            Column::new()
+                .push(highlighted_label(label, query))
+                .push(control)
+                .spacing(10)
+                .into()
+        })
+        .collect();
+
+    let results: Element<'a, Message> = if matching.is_empty() {
+        text(format!("No settings match \"{}\".", query.trim())).into()
+    } else {
+        Column::with_children(matching).spacing(30).into()
+    };
+
+    let column = Column::new()
+        .push(section_heading("Settings", settings.text_scale))
+        .push(
+            TextInput::new("Search settings... ", query)
+                .on_input(Message::SettingsQueryChanged)
+                .width(Length::Fixed(400.0))
+                .padding(Padding::from(8))
+                .style(iced::theme::TextInput::Custom(Box::new(InputStyle { focus_ring: false }))),
+        )
+        .push(results)
+        .push(
+            button(if unsaved { "Save" } else { "Saved" })
+                .on_press_maybe(unsaved.then_some(Message::SaveSettings))
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton))),
+        )
+        .padding(Padding::from([50, 20]))
+        .spacing(40);
+
+    container(column)
+        .padding(Padding::from(20))
+        .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
+}
+
+
+// label with the part matching the search query in the accent color
+fn highlighted_label<'a>(label: &'a str, query: &str) -> Row<'a, Message> {
+    match search::split_match(label, query) {
+        Some((before, matched, after)) => Row::new()
+            .push(text(before))
+            .push(text(matched).style(ACCENT))
+            .push(text(after)),
+        None => Row::new().push(text(label)),
+    }
+}
+
+
+// confirm dialog shown when leaving a page with unsaved edits
+fn discard_dialog() -> Container<'static, Message> {
+    let column = Column::new()
+        .push(text("Discard changes?").size(32))
+        .push(text("You have unsaved changes on this page."))
+        .push(
+            Row::new()
+                .push(
+                    button("Discard")
+                        .on_press(Message::DiscardChanges)
+                        .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Standard))),
+                )
+                .push(
+                    button("Keep editing")
+                        .on_press(Message::KeepEditing)
+                        .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton))),
+                )
+                .spacing(10),
+        )
+        .align_items(Alignment::Center)
+        .padding(Padding::from([50, 20]))
+        .spacing(20);
+
+    container(column)
+        .padding(Padding::from(20))
+        .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
+}
+
+
+// form fields of the current page => rendered in the configured order
+fn form_fields(app: &RustUI) -> Column<'_, Message> {
+    app.visible_fields()
+        .into_iter()
+        .fold(Column::new().spacing(20), |column, kind| column.push(form_field(app, kind)))
+}
+
+// one field => the input with its paste chip, reveal link and message underneath
+fn form_field(app: &RustUI, kind: FieldKind) -> Column<'_, Message> {
+    // the label takes over from the placeholder as soon as it starts to rise
+    let float = app.label_position(kind);
+    let placeholder = if float > 0.0 { "" } else { kind.placeholder() };
+    let input: Element<'_, Message> = if kind == FieldKind::Email && app.page == Page::Login {
+        split_email_input(app)
+    } else if let Some((_, fraction)) = app.reveal.filter(|_| kind.is_secret()) {
+        // mid reveal => a plain input over a partially masked copy, edits map back onto the value
+        let value = app.field_value(kind);
+        let shown = (fraction * value.chars().count() as f32).ceil() as usize;
+        let shown = form::partially_masked(value, shown);
+        input_field(
+            placeholder,
+            &shown,
+            app.settings.text_scale,
+            app.modality == Modality::Keyboard,
+        )
+            .width(Length::Fixed(app.field_width()))
+            .id(kind.input_id())
+            .on_input(move |edited| Message::MaskedEdit(kind, shown.clone(), edited))
+            .on_submit(Message::FieldSubmitted(kind))
+            .into()
+    } else {
+        let value = match kind {
+            FieldKind::Phone => form::format_phone(app.field_value(kind)),
+            _ => app.field_value(kind).to_string(),
+        };
+        input_field(
+            placeholder,
+            &value,
+            app.settings.text_scale,
+            app.modality == Modality::Keyboard,
+        )
+            .width(Length::Fixed(app.field_width()))
+            .id(kind.input_id())
+            .secure(kind.is_secret() && !app.password_visible)
+            .on_input(match kind {
+                FieldKind::Username => Message::UsernameChanged,
+                FieldKind::Email => Message::EmailChanged,
+                FieldKind::Password => Message::PasswordChanged,
+                FieldKind::ConfirmPassword => Message::ConfirmPasswordChanged,
+                FieldKind::Phone => Message::PhoneChanged,
+            })
+            .on_submit(Message::FieldSubmitted(kind))
+            .into()
+    };
+
+    let chip = app.paste_suggestion().filter(|_| kind == FieldKind::Email).map(paste_chip);
+    let label = floating_label(kind.label(), float, app.focused == Some(kind), app.settings.text_scale);
+    let mut field = Column::new().push_maybe(chip).push(label).push(input).spacing(5);
+    if kind == FieldKind::Password {
+        field = field.push(app.link(
+            if app.password_visible { "Hide password" } else { "Show password" },
+            Message::TogglePasswordVisibility,
+        ));
+    }
+    if let Some(error) = app.field_error(kind) {
+        field = field.push(text(error).size(14).style(ERROR_COLOR));
+    } else if kind == FieldKind::Email {
+        field = field.push_maybe(app.email_availability().map(|check| {
+            async_result_view(
+                check,
+                |check| match check {
+                    CheckResult::Available => text("Email is available.").size(14).style(ACCENT).into(),
+                    CheckResult::Taken => text("An account with this email already exists.")
+                        .size(14)
+                        .style(ERROR_COLOR)
+                        .into(),
+                },
+                Message::RetryEmailCheck,
+            )
+        }));
+    }
+    field
+}
+
+
+// label above a field => rises out of the input and shrinks as `position` goes from 0 to 1
+// the slot is always reserved so the form doesn't jump while labels move
+fn floating_label(label: &str, position: f32, focused: bool, scale: f32) -> Container<'_, Message> {
+    let slot = container(Space::with_height(Length::Fixed(0.0)))
+        .height(Length::Fixed(LABEL_SLOT_HEIGHT * scale));
+    if position <= 0.0 {
+        return slot;
+    }
+    let content = Column::new()
+        .push(Space::with_height(Length::Fixed((1.0 - position) * LABEL_RISE * scale)))
+        .push(text(label).size((16.0 - 4.0 * position) * scale));
+    container(content)
+        .height(Length::Fixed(LABEL_SLOT_HEIGHT * scale))
+        .style(iced::theme::Container::Custom(Box::new(FloatingLabelStyle { position, focused })))
+}
+
+// floating label space above each field, and how far the label travels inside it
+const LABEL_SLOT_HEIGHT: f32 = 28.0;
+const LABEL_RISE: f32 = 8.0;
+
+// smart paste => offers an email found in the clipboard, one tap to fill the field
+fn paste_chip(email: &str) -> Row<'static, Message> {
+    Row::new()
+        .push(
+            button(text(format!("Paste detected email? {email}")).size(14))
+                .on_press(Message::PasteDetectedEmail)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton))),
+        )
+        .push(
+            button(text("Dismiss").size(14))
+                .on_press(Message::DismissPasteChip)
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Link))),
+        )
+        .align_items(Alignment::Center)
+        .spacing(10)
+}
+
+
+// help overlay => the shortcuts that work on the current page
+fn help_overlay(page: &Page) -> Container<'static, Message> {
+    let column = shortcuts::shortcuts_for(page).fold(
+        Column::new().push(text("Keyboard shortcuts").size(20)).spacing(8),
+        |column, shortcut| {
+            column.push(
+                Row::new()
+                    .push(text(shortcut.label).width(Length::Fixed(80.0)).style(ACCENT))
+                    .push(text(shortcut.description))
+                    .spacing(10),
+            )
+        },
+    );
+
+    container(column)
+        .padding(Padding::from([12, 20]))
+        .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
+}
+
+
+// recent errors overlay => newest first, for diagnosing what went wrong
+fn errors_overlay(log: &ErrorLog) -> Container<'_, Message> {
+    let header = Column::new().push(text("Recent errors").size(20)).spacing(8);
+    let column = if log.is_empty() {
+        header.push(text("No errors yet."))
+    } else {
+        log.entries().rev().fold(header, |column, entry| {
+            column.push(
+                Row::new()
+                    .push(text(error_log::clock_time(entry.at)).width(Length::Fixed(110.0)))
+                    .push(text(entry.source.label()).width(Length::Fixed(90.0)).style(ACCENT))
+                    .push(text(&entry.message))
+                    .spacing(10),
+            )
+        })
+    };
+
+    container(scrollable(column).height(Length::Fixed(200.0)))
+        .padding(Padding::from([12, 20]))
+        .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
+}
+
+
+// footer bell => a badge counts the unread notifications
+fn bell(unread: usize) -> Element<'static, Message> {
+    let badge = (unread > 0).then(|| {
+        container(text(unread.min(99)).size(12).style(iced::Color::WHITE))
+            .padding(Padding::from([1, 6]))
+            .style(iced::theme::Container::Custom(Box::new(BadgeStyle)))
+    });
+    button(Row::new().push(text("\u{1F514}")).push_maybe(badge).align_items(Alignment::Center).spacing(4))
+        .on_press(Message::ToggleNotifications)
+        .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton)))
+        .into()
+}
+
+
+// notification dropdown => newest first
+fn notifications_dropdown(notifications: &Notifications) -> Container<'_, Message> {
+    let header = Column::new().push(text("Notifications").size(20)).spacing(8);
+    let column = if notifications.is_empty() {
+        header.push(text("Nothing new."))
+    } else {
+        notifications
+            .newest_first()
+            .fold(header, |column, notification| column.push(text(&notification.text)))
+    };
+
+    container(column)
+        .padding(Padding::from([12, 20]))
+        .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
+}
+
+
+// preset bar => load saved form values or save the current ones under a name
+fn preset_bar(app: &RustUI) -> Row<'_, Message> {
+    let names: Vec<String> = app.presets.iter().map(|preset| preset.name.clone()).collect();
+    Row::new()
+        .push(pick_list(names, None::<String>, Message::PresetSelected).placeholder("Load preset"))
+        .push(
+            TextInput::new("Preset name... ", &app.preset_name)
+                .on_input(Message::PresetNameChanged)
+                .on_submit(Message::SavePreset)
+                .width(Length::Fixed(180.0))
+                .padding(Padding::from(5))
+                .style(iced::theme::TextInput::Custom(Box::new(InputStyle {
+                    focus_ring: app.modality == Modality::Keyboard,
+                }))),
+        )
+        .push(
+            button("Save preset")
+                .on_press_maybe((!app.preset_name.trim().is_empty()).then_some(Message::SavePreset))
+                .style(iced::theme::Button::Custom(Box::new(ButtonStyle::ThemeButton))),
+        )
+        .align_items(Alignment::Center)
+        .spacing(10)
+}
+
+
+// what a request shows => waiting, its answer, or the error with the message that retries it
+#[derive(Debug)]
+enum AsyncState<'a, T> {
+    Loading,
+    Loaded(&'a T),
+    Failed { error: &'a ApiError, retry: Message },
+}
+
+// request state => None while waiting, the answer once it arrives, or the error to retry
+fn async_state<T>(result: Option<&Result<T, ApiError>>, on_retry: Message) -> AsyncState<'_, T> {
+    match result {
+        None => AsyncState::Loading,
+        Some(Ok(value)) => AsyncState::Loaded(value),
+        Some(Err(error)) => AsyncState::Failed { error, retry: on_retry },
+    }
+}
+
+// draws a request state => a retry button next to the error
+fn async_result_view<'a, T>(
+    result: Option<&'a Result<T, ApiError>>,
+    content: impl FnOnce(&'a T) -> Element<'a, Message>,
+    on_retry: Message,
+) -> Element<'a, Message> {
+    match async_state(result, on_retry) {
+        AsyncState::Loading => text("Checking...").size(14).into(),
+        AsyncState::Loaded(value) => content(value),
+        AsyncState::Failed { error, retry } => Row::new()
+            .push(text(error.to_string()).size(14).style(ERROR_COLOR))
+            .push(
+                button(text("Retry").size(14))
+                    .on_press(retry)
+                    .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Link))),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10)
+            .into(),
+    }
+}
+
+// toast => short notice shown above the footer
+fn toast(message: &str) -> Container<'_, Message> {
+    container(text(message).size(16))
+        .padding(Padding::from([8, 16]))
+        .style(iced::theme::Container::Custom(Box::new(ContainerStyle)))
+}
+
+
+// input field
+// login email as local part + domain combo box => together as wide as a normal field
+fn split_email_input(app: &RustUI) -> Element<'_, Message> {
+    let scale = app.settings.text_scale;
+    let focus_ring = app.modality == Modality::Keyboard;
+    let width = app.field_width();
+    let (local, domain) = form::split_email(&app.login_field.email);
+    let domain = domain.to_string();
+
+    // keeps the email input id => focus shortcuts and validation land on the local part
+    let local = input_field("name", local, scale, focus_ring)
+        .width(Length::Fixed(width * 0.5))
+        .id(FieldKind::Email.input_id())
+        .on_input(Message::EmailLocalChanged)
+        .on_submit(Message::FieldSubmitted(FieldKind::Email));
+
+    // the combo box only takes cloneable styles => it keeps the theme's default input look
+    let domain = combo_box(
+        &app.email_domains,
+        "domain",
+        (!domain.is_empty()).then_some(&domain),
+        Message::EmailDomainChanged,
+    )
+    .on_input(Message::EmailDomainChanged)
+    .width(Length::Fixed(width * 0.44))
+    .size(16.0 * scale)
+    .padding(Padding::from(10));
+
+    Row::new()
+        .push(local)
+        .push(text("@").size(16.0 * scale))
+        .push(domain)
+        .align_items(Alignment::Center)
+        .spacing(5.0 * scale)
+        .width(Length::Fixed(width))
+        .into()
+}
+
+fn input_field(_placeholder: &str, _value: &str, scale: f32, focus_ring: bool) -> TextInput<'static, Message> {
+    TextInput::new(_placeholder, _value)
+        .width(Length::Fixed(FIELD_WIDTH * scale))
+        .size(16.0 * scale)
         .padding(Padding::from(10))
         .line_height(text::LineHeight::Relative(1.75))
+        .style(iced::theme::TextInput::Custom(Box::new(InputStyle { focus_ring })))
 }
 
 // submit button
-fn submit_btn(name: &str, event: Message) -> Button<Message> {
+// sizes grow with the text scale so the label always fits
+// no event => disabled, drawn in the look picked in the settings
+fn submit_btn<'a>(name: &str, event: Option<Message>, settings: &AppSettings) -> Button<'a, Message> {
+    let scale = settings.text_scale;
     Button::new(
         text(name)
             .horizontal_alignment(Horizontal::Center)
             .vertical_alignment(Vertical::Center)
-            .size(21),
+            .size(21.0 * scale),
     )
-        .on_press(event)
-        .width(Length::Fixed(500.0))
-        .height(Length::Fixed(45.0))
+        .on_press_maybe(event)
+        .width(Length::Fixed(FIELD_WIDTH * scale))
+        .height(Length::Fixed(45.0 * scale))
         // custom style
-        .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Standard)))
+        .style(iced::theme::Button::Custom(Box::new(ButtonStyle::Submit(settings.disabled_look))))
 }
 
+// width of a form input at text scale 1
+const FIELD_WIDTH: f32 = 500.0;
+
+// single line login => narrower inputs, and the window width below which it goes back to stacked
+const LOGIN_BAR_FIELD_WIDTH: f32 = 280.0;
+const LOGIN_BAR_MIN_WIDTH: f32 = 860.0;
+
+// accent color shared by the primary button and the inputs
+const ACCENT: iced::Color = iced::Color::from_rgb(0.059, 0.463, 0.702);
+
+// color of validation messages
+const ERROR_COLOR: iced::Color = iced::Color::from_rgb(0.85, 0.2, 0.2);
+
 // button styling
 enum ButtonStyle {
     Standard,
     ThemeButton,
+    Link, // text-only action => no background, accent text
+    Submit(DisabledLook), // form submit => standard look, configurable while disabled
 }
 
 impl button::StyleSheet for ButtonStyle {
     type Style = Theme;
 
-    // define active trait => default
-    fn active(&self, theme: &Self::Style) -> button::Appearance {
-        button::Appearance {
-            background: Some(Background::Color(match self {
-                Self::Standard => iced::Color::from_rgb(0.059, 0.463, 0.702),
-                Self::ThemeButton => iced::Color::default(),
-            })),
-            border: match self {
-                Self::Standard => Border::with_radius(5),
-                Self::ThemeButton => Border::default(),
-            },
-            shadow: match self {
-                Self::Standard => Shadow {
-                    color: iced::Color::BLACK,
-                    offset: Vector::new(0.0, 0.4),
-                    blur_radius: 20.0,
-                },
-                Self::ThemeButton => Shadow::default(),
-            },
-            text_color: {
-                if theme == &Theme::Light {
-                    match self {
-                        Self::Standard => iced::Color::WHITE,
-                        Self::ThemeButton => iced::Color::WHITE,
-                    }
-                } else {
-                    match self {
-                        Self::Standard => iced::Color::BLACK,
-                        Self::ThemeButton => iced::Color::BLACK,
-                    }
-                }
-            },
-            ..Default::default()
+    // define active trait => default
+    fn active(&self, theme: &Self::Style) -> button::Appearance {
+        button::Appearance {
+            background: Some(Background::Color(match self {
+                Self::Standard | Self::Submit(_) => ACCENT,
+                Self::ThemeButton | Self::Link => iced::Color::default(),
+            })),
+            border: match self {
+                Self::Standard | Self::Submit(_) => Border::with_radius(5),
+                Self::ThemeButton | Self::Link => Border::default(),
+            },
+            shadow: match self {
+                Self::Standard | Self::Submit(_) => Shadow {
+                    color: iced::Color::BLACK,
+                    offset: Vector::new(0.0, 0.4),
+                    blur_radius: 20.0,
+                },
+                Self::ThemeButton | Self::Link => Shadow::default(),
+            },
+            text_color: {
+                if theme == &Theme::Light {
+                    match self {
+                        Self::Standard | Self::Submit(_) => iced::Color::WHITE,
+                        Self::ThemeButton => iced::Color::WHITE,
+                        Self::Link => ACCENT,
+                    }
+                } else {
+                    match self {
+                        Self::Standard | Self::Submit(_) => iced::Color::BLACK,
+                        Self::ThemeButton => iced::Color::BLACK,
+                        Self::Link => iced::Color::from_rgb(0.45, 0.72, 0.95),
+                    }
+                }
+            },
+            ..Default::default()
+        }
+    }
+
+    // links don't shift on hover => the underline is the only hover cue
+    fn hovered(&self, theme: &Self::Style) -> button::Appearance {
+        let active = self.active(theme);
+        match self {
+            Self::Link => active,
+            Self::Standard | Self::ThemeButton | Self::Submit(_) => button::Appearance {
+                shadow_offset: active.shadow_offset + Vector::new(0.0, 1.0),
+                ..active
+            },
+        }
+    }
+
+    // submit buttons follow the disabled look setting => everything else fades like iced's default
+    fn disabled(&self, theme: &Self::Style) -> button::Appearance {
+        let active = self.active(theme);
+        let light = theme == &Theme::Light;
+        match self {
+            Self::Submit(DisabledLook::Greyed) => button::Appearance {
+                background: Some(Background::Color(if light {
+                    iced::Color::from_rgb(0.8, 0.8, 0.8)
+                } else {
+                    iced::Color::from_rgb(0.3, 0.3, 0.3)
+                })),
+                text_color: if light {
+                    iced::Color::from_rgb(0.45, 0.45, 0.45)
+                } else {
+                    iced::Color::from_rgb(0.6, 0.6, 0.6)
+                },
+                shadow: Shadow::default(),
+                ..active
+            },
+            Self::Submit(DisabledLook::DimmedPrimary) => button::Appearance {
+                background: Some(Background::Color(iced::Color { a: 0.4, ..ACCENT })),
+                text_color: iced::Color { a: 0.6, ..active.text_color },
+                shadow: Shadow::default(),
+                ..active
+            },
+            Self::Standard | Self::ThemeButton | Self::Link => button::Appearance {
+                background: active.background.map(|background| match background {
+                    Background::Color(color) => Background::Color(iced::Color { a: color.a * 0.5, ..color }),
+                    other => other,
+                }),
+                text_color: iced::Color { a: active.text_color.a * 0.5, ..active.text_color },
+                ..active
+            },
+        }
+    }
+}
+
+// link underline and heading accent bar => drawn in the link color, transparent while hidden
+struct UnderlineStyle {
+    visible: bool,
+}
+
+impl container::StyleSheet for UnderlineStyle {
+    type Style = Theme;
+
+    fn appearance(&self, theme: &Self::Style) -> container::Appearance {
+        let color = button::StyleSheet::active(&ButtonStyle::Link, theme).text_color;
+        container::Appearance {
+            background: self.visible.then_some(Background::Color(color)),
+            ..Default::default()
+        }
+    }
+}
+
+// floating label => accent while its field has focus, fades in as it rises
+struct FloatingLabelStyle {
+    position: f32,
+    focused: bool,
+}
+
+impl container::StyleSheet for FloatingLabelStyle {
+    type Style = Theme;
+
+    fn appearance(&self, theme: &Self::Style) -> container::Appearance {
+        let color = if self.focused { ACCENT } else { theme.extended_palette().background.base.text };
+        container::Appearance {
+            text_color: Some(iced::Color { a: color.a * self.position, ..color }),
+            ..Default::default()
+        }
+    }
+}
+
+// labeled divider => muted lines and label that follow the theme
+struct DividerStyle {
+    line: bool, // the rule itself, otherwise the label
+}
+
+impl container::StyleSheet for DividerStyle {
+    type Style = Theme;
+
+    fn appearance(&self, theme: &Self::Style) -> container::Appearance {
+        let color = theme.extended_palette().background.strong.color;
+        if self.line {
+            container::Appearance {
+                background: Some(Background::Color(color)),
+                ..Default::default()
+            }
+        } else {
+            container::Appearance {
+                text_color: Some(color),
+                ..Default::default()
+            }
         }
     }
 }
@@ -272,7 +2676,791 @@ impl container::StyleSheet for ContainerStyle {
                 offset: Vector::new(0.0, 2.0),
                 blur_radius: 40.0,
             },
+        }
+    }
+}
+
+// text input styling => the theme defaults with an accent tinted selection
+struct InputStyle {
+    focus_ring: bool, // highlight the focused input => only while navigating by keyboard
+}
+
+impl text_input::StyleSheet for InputStyle {
+    type Style = Theme;
+
+    fn active(&self, theme: &Self::Style) -> text_input::Appearance {
+        text_input::StyleSheet::active(theme, &iced::theme::TextInput::Default)
+    }
+
+    // without a focus ring a focused input looks like an idle one
+    fn focused(&self, theme: &Self::Style) -> text_input::Appearance {
+        if !self.focus_ring {
+            return self.active(theme);
+        }
+        let appearance = text_input::StyleSheet::focused(theme, &iced::theme::TextInput::Default);
+        text_input::Appearance {
+            border: Border {
+                color: ACCENT,
+                width: 2.0,
+                ..appearance.border
+            },
+            ..appearance
+        }
+    }
+
+    fn hovered(&self, theme: &Self::Style) -> text_input::Appearance {
+        text_input::StyleSheet::hovered(theme, &iced::theme::TextInput::Default)
+    }
+
+    fn disabled(&self, theme: &Self::Style) -> text_input::Appearance {
+        text_input::StyleSheet::disabled(theme, &iced::theme::TextInput::Default)
+    }
+
+    fn placeholder_color(&self, theme: &Self::Style) -> iced::Color {
+        text_input::StyleSheet::placeholder_color(theme, &iced::theme::TextInput::Default)
+    }
+
+    // iced draws the caret with the value color => tint both so the caret stands out
+    // darker accent on Light, lighter accent on Dark keeps the typed text readable
+    fn value_color(&self, theme: &Self::Style) -> iced::Color {
+        if theme == &Theme::Light {
+            iced::Color::from_rgb(0.02, 0.25, 0.42)
+        } else {
+            iced::Color::from_rgb(0.62, 0.82, 0.96)
+        }
+    }
+
+    fn disabled_color(&self, theme: &Self::Style) -> iced::Color {
+        text_input::StyleSheet::disabled_color(theme, &iced::theme::TextInput::Default)
+    }
+
+    // translucent accent => selected text keeps the regular value color on both themes
+    fn selection_color(&self, theme: &Self::Style) -> iced::Color {
+        let alpha = if theme == &Theme::Light { 0.3 } else { 0.5 };
+        iced::Color { a: alpha, ..ACCENT }
+    }
+}
+
+// progress bar styling => accent bar on a track matching the theme
+struct ProgressStyle;
+
+impl progress_bar::StyleSheet for ProgressStyle {
+    type Style = Theme;
+
+    fn appearance(&self, theme: &Self::Style) -> progress_bar::Appearance {
+        let palette = theme.extended_palette();
+        progress_bar::Appearance {
+            background: Background::Color(palette.background.strong.color),
+            bar: Background::Color(ACCENT),
+            border_radius: 4.0.into(),
+        }
+    }
+}
+
+// stepper circle => filled when done, accent ring while running, muted ahead
+struct StepStyle {
+    state: StepState,
+    scale: f32,
+}
+
+impl container::StyleSheet for StepStyle {
+    type Style = Theme;
+
+    fn appearance(&self, theme: &Self::Style) -> container::Appearance {
+        let accent = button::StyleSheet::active(&ButtonStyle::Link, theme).text_color;
+        let palette = theme.extended_palette();
+        let radius = 14.0 * self.scale;
+        match self.state {
+            StepState::Completed => container::Appearance {
+                text_color: Some(iced::Color::WHITE),
+                background: Some(Background::Color(ACCENT)),
+                border: Border::with_radius(radius),
+                ..Default::default()
+            },
+            StepState::Active => container::Appearance {
+                text_color: Some(accent),
+                border: Border {
+                    color: accent,
+                    width: 2.0,
+                    radius: radius.into(),
+                },
+                ..Default::default()
+            },
+            StepState::Upcoming => container::Appearance {
+                text_color: Some(palette.background.strong.color),
+                border: Border {
+                    color: palette.background.strong.color,
+                    width: 1.0,
+                    radius: radius.into(),
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+// stepper line between two circles
+struct StepLineStyle {
+    lit: bool,
+}
+
+impl container::StyleSheet for StepLineStyle {
+    type Style = Theme;
+
+    fn appearance(&self, theme: &Self::Style) -> container::Appearance {
+        let color = if self.lit {
+            ACCENT
+        } else {
+            theme.extended_palette().background.strong.color
+        };
+        container::Appearance {
+            background: Some(Background::Color(color)),
+            ..Default::default()
+        }
+    }
+}
+
+// avatar styling => a filled accent circle
+struct AvatarStyle;
+
+impl container::StyleSheet for AvatarStyle {
+    type Style = Theme;
+
+    fn appearance(&self, _theme: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            background: Some(Background::Color(ACCENT)),
+            border: Border::with_radius(36),
+            ..Default::default()
+        }
+    }
+}
+
+// radio buttons => accent ring and dot, matching the inputs
+struct RadioStyle;
+
+impl radio::StyleSheet for RadioStyle {
+    type Style = Theme;
+
+    fn active(&self, theme: &Self::Style, is_selected: bool) -> radio::Appearance {
+        let accent = button::StyleSheet::active(&ButtonStyle::Link, theme).text_color;
+        let palette = theme.extended_palette();
+        radio::Appearance {
+            background: Background::Color(palette.background.base.color),
+            dot_color: accent,
+            border_width: if is_selected { 2.0 } else { 1.0 },
+            border_color: if is_selected { accent } else { palette.background.strong.color },
+            text_color: None,
+        }
+    }
+
+    fn hovered(&self, theme: &Self::Style, is_selected: bool) -> radio::Appearance {
+        let accent = button::StyleSheet::active(&ButtonStyle::Link, theme).text_color;
+        radio::Appearance {
+            border_color: accent,
+            ..self.active(theme, is_selected)
+        }
+    }
+}
+
+// toggle switch track => fades from muted to accent as the knob slides over
+struct TrackStyle {
+    position: f32,
+}
+
+impl container::StyleSheet for TrackStyle {
+    type Style = Theme;
+
+    fn appearance(&self, theme: &Self::Style) -> container::Appearance {
+        let off = theme.extended_palette().background.strong.color;
+        let mix = |from: f32, to: f32| from + (to - from) * self.position;
+        container::Appearance {
+            background: Some(Background::Color(iced::Color::from_rgb(
+                mix(off.r, ACCENT.r),
+                mix(off.g, ACCENT.g),
+                mix(off.b, ACCENT.b),
+            ))),
+            border: Border::with_radius(11),
+            ..Default::default()
+        }
+    }
+}
+
+// toggle switch knob => a white dot
+struct KnobStyle;
+
+impl container::StyleSheet for KnobStyle {
+    type Style = Theme;
+
+    fn appearance(&self, _theme: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            background: Some(Background::Color(iced::Color::WHITE)),
+            border: Border::with_radius(KNOB_SIZE / 2.0),
+            ..Default::default()
+        }
+    }
+}
+
+// unread count pill on the bell
+struct BadgeStyle;
+
+impl container::StyleSheet for BadgeStyle {
+    type Style = Theme;
+
+    fn appearance(&self, _theme: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            background: Some(Background::Color(ERROR_COLOR)),
+            border: Border::with_radius(9),
             ..Default::default()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::keyboard::{key::Named, Key, Modifiers};
+
+    // fresh app without touching the config directory
+    fn app() -> RustUI {
+        RustUI::with_state(cli::Flags::default(), AppSettings::default(), Vec::new(), WindowState::default())
+    }
+
+    // login form holding a valid email and password
+    fn filled_login() -> RustUI {
+        let mut app = app();
+        let _ = app.update(Message::EmailChanged(String::from("jane@example.com")));
+        let _ = app.update(Message::PasswordChanged(String::from("correct horse")));
+        app
+    }
+
+    #[test]
+    fn second_submit_while_busy_is_ignored() {
+        let mut app = filled_login();
+        let _ = app.update(Message::LoginSubmit);
+        let _ = app.update(Message::PasswordChanged(String::from("another password")));
+        let _ = app.update(Message::LoginSubmit);
+        assert!(matches!(
+            &app.in_flight,
+            Some(Operation::Login { password, .. }) if password == "correct horse"
+        ));
+        assert_eq!(app.toast.as_deref(), Some("Already submitting..."));
+    }
+
+    #[test]
+    fn debug_report_leaves_out_secrets() {
+        const SENTINEL: &str = "s3ntinel-passw0rd";
+        let mut app = app();
+        app.login_field.password = String::from(SENTINEL);
+        app.register_field.password = String::from(SENTINEL);
+        app.register_field.confirm_password = String::from(SENTINEL);
+        let report = app.debug_report();
+        assert!(!report.contains(SENTINEL));
+        assert!(report.contains("login password: (redacted)"));
+    }
+
+    // register page with something typed into it
+    fn dirty_register() -> RustUI {
+        let flags = cli::Flags { route: Some(String::from("Register")), demo: false };
+        let mut app = RustUI::with_state(flags, AppSettings::default(), Vec::new(), WindowState::default());
+        let _ = app.update(Message::UsernameChanged(String::from("jane")));
+        app
+    }
+
+    #[test]
+    fn leaving_a_dirty_form_is_held() {
+        let mut app = dirty_register();
+        assert!(app.has_unsaved_changes());
+        let _ = app.update(Message::Router(String::from("Login")));
+        assert_eq!(app.page, Page::Register);
+        assert!(matches!(&app.pending_navigation, Some(Message::Router(route)) if route == "Login"));
+    }
+
+    #[test]
+    fn discarding_continues_the_held_navigation() {
+        let mut app = dirty_register();
+        let _ = app.update(Message::Router(String::from("Login")));
+        let _ = app.update(Message::DiscardChanges);
+        assert_eq!(app.page, Page::Login);
+        assert!(app.pending_navigation.is_none());
+        assert!(app.register_field == RegisterField::default());
+    }
+
+    #[test]
+    fn keep_editing_cancels_the_held_navigation() {
+        let mut app = dirty_register();
+        let _ = app.update(Message::Back);
+        let _ = app.update(Message::KeepEditing);
+        assert_eq!(app.page, Page::Register);
+        assert!(app.pending_navigation.is_none());
+        assert_eq!(app.register_field.username, "jane");
+    }
+
+    #[test]
+    fn a_clean_form_leaves_right_away() {
+        let mut app = dirty_register();
+        let _ = app.update(Message::UsernameChanged(String::new()));
+        let _ = app.update(Message::Back);
+        assert_eq!(app.page, Page::Login);
+    }
+
+    #[test]
+    fn each_page_maps_to_its_main_action() {
+        assert!(matches!(Page::Login.primary_action(), Some(Message::LoginSubmit)));
+        assert!(matches!(Page::Register.primary_action(), Some(Message::RegisterSubmit)));
+        assert!(matches!(Page::Settings.primary_action(), Some(Message::SaveSettings)));
+        assert!(matches!(Page::Error.primary_action(), Some(Message::Retry)));
+        assert!(Page::Home.primary_action().is_none());
+    }
+
+    fn login_operation() -> Operation {
+        Operation::Login { email: String::from("jane@example.com"), password: String::from("correct horse") }
+    }
+
+    #[test]
+    fn expected_errors_stay_on_the_page() {
+        let mut app = app();
+        app.request_failed(ApiError::InvalidCredentials, Some(login_operation()));
+        assert_eq!(app.page, Page::Login);
+        assert!(app.failure.is_none());
+        assert_eq!(app.toast.as_deref(), Some("Incorrect email or password."));
+    }
+
+    #[test]
+    fn unexpected_errors_open_the_error_page() {
+        for error in [ApiError::Server(String::from("boom")), ApiError::Timeout] {
+            let mut app = app();
+            app.request_failed(error.clone(), Some(login_operation()));
+            assert_eq!(app.page, Page::Error);
+            assert_eq!(app.history, [Page::Login]);
+            assert!(matches!(&app.failure, Some(Failure { error: failed, .. }) if *failed == error));
+        }
+    }
+
+    #[test]
+    fn errors_without_a_request_to_retry_become_a_toast() {
+        let mut app = app();
+        app.request_failed(ApiError::Timeout, None);
+        assert_eq!(app.page, Page::Login);
+        assert_eq!(app.toast.as_deref(), Some("Server took too long."));
+    }
+
+    #[test]
+    fn navigating_forgets_the_hovered_link() {
+        let mut app = app();
+        let _ = app.update(Message::LinkHovered(Some("Page Two")));
+        let _ = app.update(Message::Router(String::from("Register")));
+        assert_eq!(app.hovered_link, None);
+
+        // opening home after logging in too
+        let mut app = filled_login();
+        let _ = app.update(Message::LoginSubmit);
+        let _ = app.update(Message::LoginResult(Ok(String::from("jane@example.com"))));
+        let _ = app.update(Message::LinkHovered(Some("Page Two")));
+        let _ = app.update(Message::ShowHome);
+        assert_eq!(app.hovered_link, None);
+    }
+
+    // register page with the password typed and focus on the confirmation
+    fn confirming_register() -> RustUI {
+        let mut app = dirty_register();
+        let _ = app.update(Message::PasswordChanged(String::from("correct horse")));
+        app.focused = Some(FieldKind::ConfirmPassword);
+        app
+    }
+
+    #[test]
+    fn mismatch_shows_only_after_leaving_the_confirmation() {
+        let mut app = confirming_register();
+        let _ = app.update(Message::ConfirmPasswordChanged(String::from("correct")));
+        assert_eq!(app.field_error(FieldKind::ConfirmPassword), None);
+
+        let _ = app.update(Message::CycleFocus(1));
+        assert_eq!(app.field_error(FieldKind::ConfirmPassword), Some("Passwords do not match."));
+
+        // matching again clears it while typing
+        let _ = app.update(Message::ConfirmPasswordChanged(String::from("correct horse")));
+        assert_eq!(app.field_error(FieldKind::ConfirmPassword), None);
+    }
+
+    #[test]
+    fn typing_clears_the_empty_confirmation_error() {
+        let mut app = confirming_register();
+        let _ = app.update(Message::CycleFocus(1));
+        assert_eq!(app.field_error(FieldKind::ConfirmPassword), Some(form::CONFIRM_REQUIRED));
+
+        let _ = app.update(Message::ConfirmPasswordChanged(String::from("c")));
+        assert_eq!(app.field_error(FieldKind::ConfirmPassword), None);
+    }
+
+    #[test]
+    fn toggling_the_theme_alternates_and_wraps() {
+        let mut app = app();
+        let mut visited = Vec::new();
+        for _ in 0..4 {
+            let _ = app.update(Message::ToggleTheme);
+            visited.push(app.theme.clone());
+        }
+        assert_eq!(visited, [Theme::Light, Theme::Dark, Theme::Light, Theme::Dark]);
+    }
+
+    #[test]
+    fn presets_are_cleaned_up_like_typed_input() {
+        let preset = Preset::new(
+            "messy",
+            [
+                (FieldKind::Username, String::from("Jane\tDoe")),
+                (FieldKind::Email, String::from("Jane@Example.COM")),
+                (FieldKind::Phone, String::from("12€4")),
+            ],
+        );
+        let long = Preset::new("long", [(FieldKind::Username, "j".repeat(form::MAX_INPUT_CHARS + 1))]);
+        let flags = cli::Flags { route: Some(String::from("Register")), demo: false };
+        let mut app = RustUI::with_state(flags, AppSettings::default(), vec![preset, long], WindowState::default());
+        let _ = app.update(Message::PresetSelected(String::from("messy")));
+        assert_eq!(app.register_field.username, "JaneDoe");
+        assert_eq!(app.register_field.email, "Jane@example.com");
+        assert_eq!(app.register_field.phone, "124");
+
+        let _ = app.update(Message::PresetSelected(String::from("long")));
+        assert_eq!(app.register_field.username, "j".repeat(form::MAX_INPUT_CHARS));
+    }
+
+    #[test]
+    fn valid_login_goes_through_to_the_session() {
+        let mut app = filled_login();
+        let _ = app.update(Message::LoginSubmit);
+        assert!(app.busy);
+        assert!(matches!(
+            &app.in_flight,
+            Some(Operation::Login { email, password }) if email == "jane@example.com" && password == "correct horse"
+        ));
+
+        let _ = app.update(Message::LoginResult(Ok(String::from("jane@example.com"))));
+        assert!(!app.busy);
+        assert!(app.errors.is_empty());
+        assert_eq!(app.session.as_ref().map(|session| session.email.as_str()), Some("jane@example.com"));
+        // home only opens once the checkmark is done
+        let started = match app.success {
+            Some((started, _, Message::ShowHome)) => started,
+            _ => panic!("expected the checkmark to lead home"),
+        };
+        assert_eq!(app.page, Page::Login);
+
+        let _ = app.update(Message::SuccessTick(started + CHECK_DRAW_DURATION + CHECK_HOLD_DURATION));
+        assert!(app.success.is_none());
+        assert_eq!(app.page, Page::Home);
+        assert!(app.history.is_empty());
+        assert!(app.errors.is_empty());
+    }
+
+    #[test]
+    fn invalid_login_is_not_sent() {
+        let mut app = app();
+        let _ = app.update(Message::EmailChanged(String::from("not an email")));
+        let _ = app.update(Message::PasswordChanged(String::from("short")));
+        let _ = app.update(Message::LoginSubmit);
+        assert!(!app.busy);
+        assert!(app.in_flight.is_none());
+        let failed: Vec<FieldKind> = app.errors.iter().map(|(kind, _)| *kind).collect();
+        assert_eq!(failed, [FieldKind::Email, FieldKind::Password]);
+        assert_eq!(app.focused, Some(FieldKind::Email));
+    }
+
+    // login page showing an email error, with live validation debounced
+    fn debounced_login() -> RustUI {
+        let mut app = app();
+        let _ = app.update(Message::DebounceChanged(300));
+        let _ = app.update(Message::LoginSubmit);
+        assert!(app.field_error(FieldKind::Email).is_some());
+        app
+    }
+
+    #[test]
+    fn edits_wait_for_typing_to_settle() {
+        let mut app = debounced_login();
+        let _ = app.update(Message::EmailChanged(String::from("jane@example.com")));
+        assert!(app.field_error(FieldKind::Email).is_some());
+
+        let _ = app.update(Message::InputSettled(app.edit_generation));
+        assert_eq!(app.field_error(FieldKind::Email), None);
+    }
+
+    #[test]
+    fn only_the_latest_settle_validates() {
+        let mut app = debounced_login();
+        let _ = app.update(Message::EmailChanged(String::from("jane@")));
+        let stale = app.edit_generation;
+        let _ = app.update(Message::EmailChanged(String::from("jane@example.com")));
+
+        // the timer of the earlier keystroke fires late => ignored
+        let _ = app.update(Message::InputSettled(stale));
+        assert!(app.field_error(FieldKind::Email).is_some());
+        assert_eq!(app.unsettled, Some(FieldKind::Email));
+
+        // both keystrokes coalesce into a single check
+        let _ = app.update(Message::InputSettled(app.edit_generation));
+        assert_eq!(app.field_error(FieldKind::Email), None);
+        assert_eq!(app.unsettled, None);
+    }
+
+    #[test]
+    fn typing_an_at_keeps_the_picked_domain() {
+        let mut app = app();
+        let _ = app.update(Message::EmailDomainChanged(String::from("gmail.com")));
+        let _ = app.update(Message::EmailLocalChanged(String::from("jane")));
+        let _ = app.update(Message::EmailLocalChanged(String::from("jane@")));
+        assert_eq!(app.login_field.email, "jane@gmail.com");
+
+        // a pasted address => split across the local part and the domain picker
+        let _ = app.update(Message::EmailLocalChanged(String::from("jane@other.org")));
+        assert_eq!(form::split_email(&app.login_field.email), ("jane", "other.org"));
+    }
+
+    #[test]
+    fn only_changed_settings_are_autosaved() {
+        let mut app = app();
+        let _ = app.update(Message::Router(String::from("Settings")));
+        assert_eq!(app.page, Page::Settings);
+        assert!(!app.needs_autosave());
+
+        // nothing changed => leaving writes nothing and goes right away
+        assert!(app.autosave_settings());
+        assert!(app.notifications.is_empty());
+
+        let enter_submits = app.settings.enter_submits;
+        let _ = app.update(Message::EnterSubmitsToggled(!enter_submits));
+        assert!(app.needs_autosave());
+
+        // back to the saved value => clean again
+        let _ = app.update(Message::EnterSubmitsToggled(enter_submits));
+        assert!(!app.needs_autosave());
+    }
+
+    #[test]
+    fn changes_elsewhere_are_not_autosaved() {
+        let mut app = dirty_register();
+        let enter_submits = app.settings.enter_submits;
+        let _ = app.update(Message::EnterSubmitsToggled(!enter_submits));
+        assert!(!app.needs_autosave());
+    }
+
+    #[test]
+    fn an_unplaceable_edit_during_the_reveal_just_finishes_it() {
+        let mut app = app();
+        let _ = app.update(Message::PasswordChanged(String::from("abcd")));
+        app.password_visible = true;
+        app.reveal = Some((Instant::now(), 0.5));
+        let shown = form::partially_masked("abcd", 2);
+
+        let _ = app.update(Message::MaskedEdit(FieldKind::Password, shown, format!("ab{}", form::MASK)));
+        assert_eq!(app.reveal, None);
+        assert_eq!(app.login_field.password, "abcd");
+    }
+
+    #[test]
+    fn typing_past_a_complete_phone_number_is_ignored() {
+        let mut app = app();
+        let _ = app.update(Message::Router(String::from("Register")));
+        let _ = app.update(Message::PhoneChanged(String::from("1234567890")));
+        assert_eq!(app.register_field.phone, "1234567890");
+
+        let _ = app.update(Message::PhoneChanged(String::from("(123) 456-78905")));
+        assert_eq!(app.register_field.phone, "1234567890");
+    }
+
+    #[test]
+    fn pasting_a_number_with_its_country_code_drops_the_code() {
+        let mut app = app();
+        let _ = app.update(Message::Router(String::from("Register")));
+        let _ = app.update(Message::PhoneChanged(String::from("+1 (123) 456-7890")));
+        assert_eq!(app.register_field.phone, "1234567890");
+    }
+
+    #[test]
+    fn only_the_email_domain_is_lowercased() {
+        let mut app = app();
+        let _ = app.update(Message::EmailChanged(String::from("Jane@Example.COM")));
+        let _ = app.update(Message::PasswordChanged(String::from("Correct Horse")));
+        assert_eq!(app.login_field.email, "Jane@example.com");
+        assert_eq!(app.login_field.password, "Correct Horse");
+
+        // turned off => typed as is
+        let _ = app.update(Message::LowercaseDomainToggled(false));
+        let _ = app.update(Message::EmailChanged(String::from("Jane@Example.COM")));
+        assert_eq!(app.login_field.email, "Jane@Example.COM");
+    }
+
+    #[test]
+    fn dismiss_closes_the_topmost_overlay_first() {
+        let mut app = app();
+        app.toast = Some(String::from("Saved"));
+        let _ = app.update(Message::ToggleErrors);
+        let _ = app.update(Message::ToggleHelp);
+        let _ = app.update(Message::ToggleNotifications);
+
+        let _ = app.update(Message::Dismiss);
+        assert!(!app.show_notifications);
+        assert!(app.show_help && app.show_errors);
+
+        let _ = app.update(Message::Dismiss);
+        assert!(!app.show_help);
+        assert!(app.show_errors);
+
+        let _ = app.update(Message::Dismiss);
+        assert!(!app.show_errors);
+        assert!(app.toast.is_some());
+
+        let _ = app.update(Message::Dismiss);
+        assert_eq!(app.toast, None);
+    }
+
+    #[test]
+    fn dismiss_answers_the_discard_dialog_before_overlays() {
+        let mut app = dirty_register();
+        let _ = app.update(Message::ToggleHelp);
+        let _ = app.update(Message::Router(String::from("Login")));
+        assert!(app.pending_navigation.is_some());
+
+        // like keep editing => stays on the page with the form intact
+        let _ = app.update(Message::Dismiss);
+        assert!(app.pending_navigation.is_none());
+        assert_eq!(app.page, Page::Register);
+        assert!(app.show_help);
+    }
+
+    #[test]
+    fn dismiss_with_only_a_toast_clears_it() {
+        let mut app = app();
+        app.toast = Some(String::from("Saved"));
+        let _ = app.update(Message::Dismiss);
+        assert_eq!(app.toast, None);
+        assert!(!app.show_help && !app.show_errors && !app.show_notifications);
+    }
+
+    #[test]
+    fn pending_requests_show_as_loading() {
+        let state = async_state::<CheckResult>(None, Message::RetryEmailCheck);
+        assert!(matches!(state, AsyncState::Loading));
+    }
+
+    #[test]
+    fn answers_show_their_value() {
+        let result = Ok(CheckResult::Available);
+        let state = async_state(Some(&result), Message::RetryEmailCheck);
+        assert!(matches!(state, AsyncState::Loaded(CheckResult::Available)));
+    }
+
+    #[test]
+    fn failed_requests_offer_a_retry() {
+        let result: Result<CheckResult, ApiError> = Err(ApiError::Timeout);
+        let state = async_state(Some(&result), Message::RetryEmailCheck);
+        assert!(matches!(
+            state,
+            AsyncState::Failed { error: ApiError::Timeout, retry: Message::RetryEmailCheck }
+        ));
+    }
+
+    #[test]
+    fn failed_login_clears_the_password() {
+        let mut app = filled_login();
+        let _ = app.update(Message::LoginSubmit);
+        let _ = app.update(Message::LoginResult(Err(ApiError::InvalidCredentials)));
+        assert_eq!(app.login_field.password, "");
+        assert_eq!(app.login_field.email, "jane@example.com");
+    }
+
+    #[test]
+    fn failed_login_can_keep_the_password() {
+        let mut app = filled_login();
+        let _ = app.update(Message::ClearPasswordToggled(false));
+        let _ = app.update(Message::LoginSubmit);
+        let _ = app.update(Message::LoginResult(Err(ApiError::InvalidCredentials)));
+        assert_eq!(app.login_field.password, "correct horse");
+    }
+
+    #[test]
+    fn retry_after_a_wiped_password_asks_for_it_again() {
+        let mut app = filled_login();
+        let _ = app.update(Message::LoginSubmit);
+        let _ = app.update(Message::LoginResult(Err(ApiError::Timeout)));
+        assert_eq!(app.page, Page::Error);
+        assert_eq!(app.login_field.password, "");
+        assert!(matches!(
+            app.failure.as_ref().map(|failure| &failure.operation),
+            Some(Operation::Login { email, password }) if email == "jane@example.com" && password.is_empty()
+        ));
+
+        // nothing to resend => back to the form with the password focused
+        let _ = app.update(Message::Retry);
+        assert_eq!(app.page, Page::Login);
+        assert!(app.in_flight.is_none());
+        assert_eq!(app.focused, Some(FieldKind::Password));
+    }
+
+    #[test]
+    fn retry_resends_a_kept_password() {
+        let mut app = filled_login();
+        let _ = app.update(Message::ClearPasswordToggled(false));
+        let _ = app.update(Message::LoginSubmit);
+        let _ = app.update(Message::LoginResult(Err(ApiError::Timeout)));
+        let _ = app.update(Message::Retry);
+        assert!(matches!(
+            &app.in_flight,
+            Some(Operation::Login { password, .. }) if password == "correct horse"
+        ));
+    }
+
+    #[test]
+    fn pasted_tabs_are_dropped() {
+        let mut app = app();
+        let _ = app.update(Message::EmailChanged(String::from("jane@example.com\t")));
+        let _ = app.update(Message::PasswordChanged(String::from("correct\thorse")));
+        assert_eq!(app.login_field.email, "jane@example.com");
+        assert_eq!(app.login_field.password, "correcthorse");
+    }
+
+    #[test]
+    fn tab_moves_focus_without_touching_the_fields() {
+        let mut app = filled_login();
+        app.focused = Some(FieldKind::Email);
+        let tab = Message::KeyPressed(Key::Named(Named::Tab), Modifiers::empty(), true);
+        let _ = app.update(tab);
+        assert_eq!(app.focused, Some(FieldKind::Password));
+        assert_eq!(app.login_field.email, "jane@example.com");
+        assert_eq!(app.login_field.password, "correct horse");
+    }
+
+    #[test]
+    fn autofocus_alone_does_not_validate_on_leave() {
+        let mut app = app();
+        let _ = app.update(Message::Router(String::from("Register")));
+        assert_eq!(app.focused, Some(FieldKind::Email));
+
+        let _ = app.update(Message::UsernameChanged(String::from("j")));
+        assert_eq!(app.focused, Some(FieldKind::Username));
+        assert_eq!(app.field_error(FieldKind::Email), None);
+    }
+
+    #[test]
+    fn an_autofocused_field_is_checked_once_typed_in() {
+        let mut app = app();
+        let _ = app.update(Message::Router(String::from("Register")));
+        let _ = app.update(Message::EmailChanged(String::from("jane@")));
+        let _ = app.update(Message::UsernameChanged(String::from("j")));
+        assert!(app.field_error(FieldKind::Email).is_some());
+    }
+
+    #[test]
+    fn submitting_during_the_checkmark_is_ignored() {
+        let mut app = filled_login();
+        let _ = app.update(Message::LoginSubmit);
+        let _ = app.update(Message::LoginResult(Ok(String::from("jane@example.com"))));
+        assert!(!app.busy && app.success.is_some());
+
+        // the password was cleared on success => type it again so only the guard can stop the submit
+        let _ = app.update(Message::PasswordChanged(String::from("correct horse")));
+        let _ = app.update(Message::LoginSubmit);
+        assert!(app.in_flight.is_none());
+        assert!(!app.busy);
+    }
+}
@@ -0,0 +1,89 @@
+// notices collected for the footer bell => unread ones are counted on its badge
+
+// how many notifications are kept => oldest drop off
+const LIMIT: usize = 20;
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub text: String,
+    pub read: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct Notifications {
+    items: Vec<Notification>,
+}
+
+impl Notifications {
+    pub fn push(&mut self, text: impl Into<String>) {
+        self.items.push(Notification {
+            text: text.into(),
+            read: false,
+        });
+        if self.items.len() > LIMIT {
+            self.items.remove(0);
+        }
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.items.iter().filter(|notification| !notification.read).count()
+    }
+
+    pub fn mark_all_read(&mut self) {
+        for notification in &mut self.items {
+            notification.read = true;
+        }
+    }
+
+    // newest first
+    pub fn newest_first(&self) -> impl Iterator<Item = &Notification> {
+        self.items.iter().rev()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_notifications_count_as_unread() {
+        let mut notifications = Notifications::default();
+        assert!(notifications.is_empty());
+        assert_eq!(notifications.unread_count(), 0);
+
+        notifications.push("Saved");
+        notifications.push("Copied");
+        assert_eq!(notifications.unread_count(), 2);
+    }
+
+    #[test]
+    fn marking_read_clears_the_badge() {
+        let mut notifications = Notifications::default();
+        notifications.push("Saved");
+        notifications.mark_all_read();
+        assert_eq!(notifications.unread_count(), 0);
+
+        notifications.push("Copied");
+        assert_eq!(notifications.unread_count(), 1);
+    }
+
+    #[test]
+    fn oldest_drop_off_past_the_limit() {
+        let mut notifications = Notifications::default();
+        notifications.push("first");
+        notifications.mark_all_read();
+        for n in 0..LIMIT {
+            notifications.push(format!("notice {n}"));
+        }
+
+        // the read one dropped off => every kept one is unread
+        assert_eq!(notifications.newest_first().count(), LIMIT);
+        assert_eq!(notifications.unread_count(), LIMIT);
+        assert_eq!(notifications.newest_first().last().unwrap().text, "notice 0");
+        assert_eq!(notifications.newest_first().next().unwrap().text, format!("notice {}", LIMIT - 1));
+    }
+}
@@ -0,0 +1,114 @@
+// named sets of form values => passwords are never stored
+use std::fs;
+use std::io;
+
+use crate::form::FieldKind;
+use crate::settings;
+
+const PRESETS_FILE: &str = "presets.conf";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preset {
+    pub name: String,
+    pub fields: Vec<(FieldKind, String)>, // non-secret field values
+}
+
+impl Preset {
+    // keep only the fields that are safe to store
+    pub fn new(name: &str, fields: impl IntoIterator<Item = (FieldKind, String)>) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            fields: fields
+                .into_iter()
+                .filter(|(kind, _)| !kind.is_secret())
+                .collect(),
+        }
+    }
+
+    pub fn value(&self, kind: FieldKind) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+// load the presets file => missing or unreadable files mean no presets
+pub fn load() -> Vec<Preset> {
+    match settings::config_file(PRESETS_FILE).map(fs::read_to_string) {
+        Some(Ok(contents)) => parse(&contents),
+        _ => Vec::new(),
+    }
+}
+
+pub fn save(presets: &[Preset]) -> io::Result<()> {
+    settings::write_config_file(PRESETS_FILE, &serialize(presets))
+}
+
+// `[name]` headers followed by `field=value` lines
+pub fn parse(contents: &str) -> Vec<Preset> {
+    let mut presets: Vec<Preset> = Vec::new();
+    for line in contents.lines() {
+        if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+            presets.push(Preset::new(name, []));
+        } else if let (Some(preset), Some((key, value))) = (presets.last_mut(), line.split_once('=')) {
+            if let Some(kind) = FieldKind::from_key(key).filter(|kind| !kind.is_secret()) {
+                preset.fields.push((kind, value.to_string()));
+            }
+        }
+    }
+    presets
+}
+
+pub fn serialize(presets: &[Preset]) -> String {
+    presets
+        .iter()
+        .map(|preset| {
+            let fields: String = preset
+                .fields
+                .iter()
+                .filter(|(kind, _)| !kind.is_secret())
+                .map(|(kind, value)| format!("{}={}\n", kind.key(), value))
+                .collect();
+            format!("[{}]\n{}", preset.name, fields)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_survive_a_round_trip() {
+        let presets = vec![
+            Preset::new(
+                "work",
+                [
+                    (FieldKind::Username, String::from("jane")),
+                    (FieldKind::Email, String::from("jane@work.example")),
+                ],
+            ),
+            Preset::new("home", [(FieldKind::Phone, String::from("5551234567"))]),
+        ];
+        assert_eq!(parse(&serialize(&presets)), presets);
+    }
+
+    #[test]
+    fn passwords_are_never_kept() {
+        let preset = Preset::new(
+            "secret",
+            [
+                (FieldKind::Email, String::from("jane@example.com")),
+                (FieldKind::Password, String::from("hunter22")),
+                (FieldKind::ConfirmPassword, String::from("hunter22")),
+            ],
+        );
+        assert_eq!(preset.value(FieldKind::Password), None);
+        assert!(!serialize(&[preset]).contains("hunter22"));
+
+        // a hand-edited file can still hold one => dropped while reading
+        let parsed = parse("[secret]\nemail=jane@example.com\npassword=hunter22\n");
+        assert_eq!(parsed[0].fields, [(FieldKind::Email, String::from("jane@example.com"))]);
+    }
+}
@@ -0,0 +1,59 @@
+// case-insensitive label matching for the settings search box
+
+// true when the label contains the query => an empty query matches everything
+pub fn matches(label: &str, query: &str) -> bool {
+    query.trim().is_empty() || split_match(label, query).is_some()
+}
+
+// split a label around the first occurrence of the query => (before, matched, after)
+pub fn split_match<'a>(label: &'a str, query: &str) -> Option<(&'a str, &'a str, &'a str)> {
+    let query = query.trim();
+    if query.is_empty() {
+        return None;
+    }
+    let lower_label = label.to_lowercase();
+    let lower_query = query.to_lowercase();
+    // lowercasing can change byte lengths for some scripts => only split when offsets line up
+    if lower_label.len() != label.len() {
+        let start = label.find(query)?;
+        return Some(split_at(label, start, query.len()));
+    }
+    let start = lower_label.find(&lower_query)?;
+    Some(split_at(label, start, lower_query.len()))
+}
+
+fn split_at(label: &str, start: usize, len: usize) -> (&str, &str, &str) {
+    let (before, rest) = label.split_at(start);
+    let (matched, after) = rest.split_at(len);
+    (before, matched, after)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_ignores_case() {
+        assert!(matches("Text scale", "SCALE"));
+        assert_eq!(split_match("Text scale", "SCALE"), Some(("Text ", "scale", "")));
+        assert_eq!(split_match("Reduce motion", "duce M"), Some(("Re", "duce m", "otion")));
+    }
+
+    #[test]
+    fn empty_query_matches_without_highlighting() {
+        assert!(matches("Text scale", ""));
+        assert!(matches("Text scale", "   "));
+        assert_eq!(split_match("Text scale", " "), None);
+    }
+
+    #[test]
+    fn unrelated_query_matches_nothing() {
+        assert!(!matches("Text scale", "timeout"));
+        assert_eq!(split_match("Text scale", "timeout"), None);
+    }
+
+    #[test]
+    fn query_is_trimmed() {
+        assert_eq!(split_match("Field order", "  order "), Some(("Field ", "order", "")));
+    }
+}
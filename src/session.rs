@@ -0,0 +1,159 @@
+// signed in session and human readable times around it
+use std::time::{Duration, Instant};
+
+// how long a session stays valid after logging in
+pub const SESSION_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
+// time left when the user gets told the session is about to end
+pub const EXPIRY_NOTICE: Duration = Duration::from_secs(2 * 60);
+
+// time left when the stay signed in banner appears
+pub const EXPIRY_WARNING: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub email: String,
+    pub logged_in_at: Instant,
+    pub active_at: Instant,    // the timeout counts from here => moved by staying signed in
+    pub expiry_notified: bool, // the expiring soon notification was sent
+    pub warned: bool,          // the stay signed in banner came up for this countdown
+}
+
+impl Session {
+    pub fn new(email: String) -> Self {
+        let now = Instant::now();
+        Self {
+            email,
+            logged_in_at: now,
+            active_at: now,
+            expiry_notified: false,
+            warned: false,
+        }
+    }
+
+    pub fn expires_at(&self) -> Instant {
+        self.active_at + SESSION_TIMEOUT
+    }
+
+    // the banner comes up once per countdown, in its last minute
+    pub fn warning_due(&self, now: Instant) -> bool {
+        !self.warned && self.expires_at().saturating_duration_since(now) <= EXPIRY_WARNING
+    }
+
+    // stay signed in => a fresh countdown from now
+    pub fn extend(&mut self, now: Instant) {
+        self.active_at = now;
+        self.expiry_notified = false;
+        self.warned = false;
+    }
+}
+
+// up to two initials from the local part of an email => "jane.doe@x.com" gives "JD"
+pub fn initials(email: &str) -> String {
+    let local = email.split('@').next().unwrap_or_default();
+    let initials: String = local
+        .split(['.', '_', '-', '+'])
+        .filter_map(|part| part.chars().next())
+        .filter(|c| c.is_alphanumeric())
+        .take(2)
+        .flat_map(char::to_uppercase)
+        .collect();
+    if initials.is_empty() {
+        String::from("?")
+    } else {
+        initials
+    }
+}
+
+// "just now", "1 minute", "3 hours" => the duration part of a relative time
+fn amount(duration: Duration) -> Option<String> {
+    let seconds = duration.as_secs();
+    let (value, unit) = if seconds < 60 {
+        return None;
+    } else if seconds < 60 * 60 {
+        (seconds / 60, "minute")
+    } else if seconds < 24 * 60 * 60 {
+        (seconds / (60 * 60), "hour")
+    } else {
+        (seconds / (24 * 60 * 60), "day")
+    };
+    Some(format!("{value} {unit}{}", if value == 1 { "" } else { "s" }))
+}
+
+// time since something happened => "logged in 2 minutes ago"
+pub fn time_ago(elapsed: Duration) -> String {
+    match amount(elapsed) {
+        Some(amount) => format!("{amount} ago"),
+        None => String::from("just now"),
+    }
+}
+
+// time until something happens => "expires in 13 minutes"
+pub fn time_until(remaining: Duration) -> String {
+    match amount(remaining) {
+        Some(amount) => format!("in {amount}"),
+        None if remaining.is_zero() => String::from("now"),
+        None => String::from("in less than a minute"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(seconds: u64) -> Duration {
+        Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn time_ago_rounds_down_to_whole_units() {
+        assert_eq!(time_ago(secs(0)), "just now");
+        assert_eq!(time_ago(secs(59)), "just now");
+        assert_eq!(time_ago(secs(60)), "1 minute ago");
+        assert_eq!(time_ago(secs(119)), "1 minute ago");
+        assert_eq!(time_ago(secs(120)), "2 minutes ago");
+        assert_eq!(time_ago(secs(60 * 60 - 1)), "59 minutes ago");
+        assert_eq!(time_ago(secs(60 * 60)), "1 hour ago");
+        assert_eq!(time_ago(secs(5 * 60 * 60)), "5 hours ago");
+        assert_eq!(time_ago(secs(24 * 60 * 60)), "1 day ago");
+        assert_eq!(time_ago(secs(3 * 24 * 60 * 60)), "3 days ago");
+    }
+
+    #[test]
+    fn time_until_says_now_at_zero() {
+        assert_eq!(time_until(secs(0)), "now");
+        assert_eq!(time_until(secs(59)), "in less than a minute");
+        assert_eq!(time_until(secs(60)), "in 1 minute");
+        assert_eq!(time_until(secs(14 * 60 + 30)), "in 14 minutes");
+        assert_eq!(time_until(secs(2 * 60 * 60)), "in 2 hours");
+        assert_eq!(time_until(secs(24 * 60 * 60)), "in 1 day");
+    }
+
+    #[test]
+    fn warning_comes_up_once_in_the_last_minute() {
+        let mut session = Session::new(String::from("jane@example.com"));
+        let expires = session.expires_at();
+        assert!(!session.warning_due(session.logged_in_at));
+        assert!(!session.warning_due(expires - EXPIRY_WARNING - secs(1)));
+        assert!(session.warning_due(expires - EXPIRY_WARNING));
+        assert!(session.warning_due(expires));
+
+        // shown => not due again for this countdown
+        session.warned = true;
+        assert!(!session.warning_due(expires - secs(10)));
+    }
+
+    #[test]
+    fn staying_signed_in_rearms_the_warning() {
+        let mut session = Session::new(String::from("jane@example.com"));
+        let now = session.expires_at() - secs(30);
+        session.warned = true;
+        session.expiry_notified = true;
+
+        session.extend(now);
+        assert_eq!(session.expires_at(), now + SESSION_TIMEOUT);
+        assert!(!session.expiry_notified);
+        assert!(!session.warning_due(now));
+        assert!(session.warning_due(session.expires_at() - EXPIRY_WARNING));
+    }
+}
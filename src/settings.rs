@@ -0,0 +1,392 @@
+// user settings persisted between runs as simple `key=value` lines
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use iced::alignment::Vertical;
+
+use crate::form::{self, FieldKind};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppSettings {
+    pub field_order: Vec<FieldKind>, // order the form fields are rendered in
+    pub text_scale: f32,             // multiplier applied to form text and sizes
+    pub enter_submits: bool,         // enter anywhere in a form runs the page's primary action
+    pub borderless: bool,            // no OS title bar => the app draws its own title strip
+    pub disabled_look: DisabledLook, // how a submit button that can't be pressed yet is drawn
+    pub debounce_ms: u32,            // quiet time before live validation runs, 0 = immediately
+    pub alignment: ContentAlignment, // where page content sits vertically
+    pub reduce_motion: bool,         // skip decorative animations
+    pub request_timeout_secs: u32,   // how long a backend request may take before giving up
+    pub lowercase_email_domain: bool, // email domains are lowercased as they're typed
+    pub login_layout: LoginLayout,   // how the login form is laid out
+    pub clear_password_on_failure: bool, // a rejected login empties the password field
+    pub initial_focus: InitialFocus, // field focused when a page opens
+}
+
+// what gets focus when a form page opens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InitialFocus {
+    #[default]
+    Email,
+    Nothing, // no autofocus => e.g. keeps an on-screen keyboard from popping up
+}
+
+impl InitialFocus {
+    pub const ALL: [InitialFocus; 2] = [InitialFocus::Email, InitialFocus::Nothing];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            InitialFocus::Email => "email",
+            InitialFocus::Nothing => "none",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<InitialFocus> {
+        InitialFocus::ALL.into_iter().find(|focus| focus.key() == key.trim())
+    }
+
+    // the field to focus among the ones a page shows => None when the page has no such field
+    pub fn target(self, fields: &[FieldKind]) -> Option<FieldKind> {
+        match self {
+            InitialFocus::Email => fields.iter().copied().find(|kind| *kind == FieldKind::Email),
+            InitialFocus::Nothing => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InitialFocus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            InitialFocus::Email => "Email field",
+            InitialFocus::Nothing => "Nothing",
+        })
+    }
+}
+
+// arrangement of the login form
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LoginLayout {
+    #[default]
+    Stacked, // one field per line
+    Bar,     // email, password and the button on one line => falls back to stacked when narrow
+}
+
+impl LoginLayout {
+    pub const ALL: [LoginLayout; 2] = [LoginLayout::Stacked, LoginLayout::Bar];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            LoginLayout::Stacked => "stacked",
+            LoginLayout::Bar => "bar",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<LoginLayout> {
+        LoginLayout::ALL.into_iter().find(|layout| layout.key() == key.trim())
+    }
+}
+
+impl std::fmt::Display for LoginLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LoginLayout::Stacked => "Stacked",
+            LoginLayout::Bar => "Single line",
+        })
+    }
+}
+
+// vertical placement of the page content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContentAlignment {
+    #[default]
+    Center,
+    Top, // forms anchored near the top of the window
+}
+
+impl ContentAlignment {
+    pub const ALL: [ContentAlignment; 2] = [ContentAlignment::Center, ContentAlignment::Top];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            ContentAlignment::Center => "center",
+            ContentAlignment::Top => "top",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<ContentAlignment> {
+        ContentAlignment::ALL.into_iter().find(|alignment| alignment.key() == key.trim())
+    }
+
+    pub fn vertical(self) -> Vertical {
+        match self {
+            ContentAlignment::Center => Vertical::Center,
+            ContentAlignment::Top => Vertical::Top,
+        }
+    }
+}
+
+impl std::fmt::Display for ContentAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ContentAlignment::Center => "Centered",
+            ContentAlignment::Top => "Top",
+        })
+    }
+}
+
+// appearance of a disabled submit button
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisabledLook {
+    #[default]
+    Greyed,        // neutral grey => clearly inactive
+    DimmedPrimary, // faded accent => keeps the brand color
+}
+
+impl DisabledLook {
+    pub const ALL: [DisabledLook; 2] = [DisabledLook::Greyed, DisabledLook::DimmedPrimary];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            DisabledLook::Greyed => "greyed",
+            DisabledLook::DimmedPrimary => "dimmed-primary",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<DisabledLook> {
+        DisabledLook::ALL.into_iter().find(|look| look.key() == key.trim())
+    }
+}
+
+// shown in the settings pick list
+impl std::fmt::Display for DisabledLook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DisabledLook::Greyed => "Greyed",
+            DisabledLook::DimmedPrimary => "Dimmed primary",
+        })
+    }
+}
+
+// bounds of the text scale setting
+pub const MIN_TEXT_SCALE: f32 = 0.75;
+pub const MAX_TEXT_SCALE: f32 = 2.0;
+
+// bounds of the request timeout setting
+pub const MIN_REQUEST_TIMEOUT_SECS: u32 = 1;
+pub const MAX_REQUEST_TIMEOUT_SECS: u32 = 60;
+
+// upper bound of the input debounce setting
+pub const MAX_DEBOUNCE_MS: u32 = 1000;
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            field_order: FieldKind::ALL.to_vec(),
+            text_scale: 1.0,
+            enter_submits: false,
+            borderless: false,
+            disabled_look: DisabledLook::default(),
+            debounce_ms: 0,
+            alignment: ContentAlignment::default(),
+            reduce_motion: false,
+            request_timeout_secs: 10,
+            lowercase_email_domain: true,
+            login_layout: LoginLayout::default(),
+            clear_password_on_failure: true,
+            initial_focus: InitialFocus::default(),
+        }
+    }
+}
+
+impl AppSettings {
+    // load the settings file => falls back to the defaults when missing or unreadable
+    pub fn load() -> Self {
+        match config_file("settings.conf").map(fs::read_to_string) {
+            Some(Ok(contents)) => Self::parse(&contents),
+            _ => Self::default(),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        write_config_file("settings.conf", &self.serialize())
+    }
+
+    // unknown keys and malformed values are ignored
+    pub fn parse(contents: &str) -> Self {
+        let mut settings = Self::default();
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            match key.trim() {
+                "field_order" => {
+                    let order = value.split(',').filter_map(FieldKind::from_key).collect();
+                    settings.field_order = form::normalize_order(order);
+                }
+                "text_scale" => {
+                    if let Ok(scale) = value.trim().parse() {
+                        settings.text_scale = clamp_text_scale(scale);
+                    }
+                }
+                "enter_submits" => settings.enter_submits = value.trim() == "true",
+                "borderless" => settings.borderless = value.trim() == "true",
+                "reduce_motion" => settings.reduce_motion = value.trim() == "true",
+                "lowercase_email_domain" => settings.lowercase_email_domain = value.trim() == "true",
+                "clear_password_on_failure" => settings.clear_password_on_failure = value.trim() == "true",
+                "debounce_ms" => {
+                    if let Ok(ms) = value.trim().parse::<u32>() {
+                        settings.debounce_ms = ms.min(MAX_DEBOUNCE_MS);
+                    }
+                }
+                "request_timeout" => {
+                    if let Ok(secs) = value.trim().parse::<u32>() {
+                        settings.request_timeout_secs =
+                            secs.clamp(MIN_REQUEST_TIMEOUT_SECS, MAX_REQUEST_TIMEOUT_SECS);
+                    }
+                }
+                "initial_focus" => {
+                    if let Some(focus) = InitialFocus::from_key(value) {
+                        settings.initial_focus = focus;
+                    }
+                }
+                "login_layout" => {
+                    if let Some(layout) = LoginLayout::from_key(value) {
+                        settings.login_layout = layout;
+                    }
+                }
+                "alignment" => {
+                    if let Some(alignment) = ContentAlignment::from_key(value) {
+                        settings.alignment = alignment;
+                    }
+                }
+                "disabled_look" => {
+                    if let Some(look) = DisabledLook::from_key(value) {
+                        settings.disabled_look = look;
+                    }
+                }
+                _ => {}
+            }
+        }
+        settings
+    }
+
+    pub fn serialize(&self) -> String {
+        let order: Vec<&str> = self.field_order.iter().map(|kind| kind.key()).collect();
+        format!(
+            "field_order={}\ntext_scale={}\nenter_submits={}\nborderless={}\ndisabled_look={}\ndebounce_ms={}\nalignment={}\nreduce_motion={}\nrequest_timeout={}\nlowercase_email_domain={}\nlogin_layout={}\nclear_password_on_failure={}\ninitial_focus={}\n",
+            order.join(","),
+            self.text_scale,
+            self.enter_submits,
+            self.borderless,
+            self.disabled_look.key(),
+            self.debounce_ms,
+            self.alignment.key(),
+            self.reduce_motion,
+            self.request_timeout_secs,
+            self.lowercase_email_domain,
+            self.login_layout.key(),
+            self.clear_password_on_failure,
+            self.initial_focus.key(),
+        )
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_secs(self.request_timeout_secs.into())
+    }
+
+    // move a field one slot up (-1) or down (1) in the order
+    pub fn move_field(&mut self, kind: FieldKind, offset: isize) {
+        let Some(index) = self.field_order.iter().position(|k| *k == kind) else {
+            return;
+        };
+        let target = index as isize + offset;
+        if target >= 0 && (target as usize) < self.field_order.len() {
+            self.field_order.swap(index, target as usize);
+        }
+    }
+}
+
+// keep the text scale within the supported range
+pub fn clamp_text_scale(scale: f32) -> f32 {
+    if scale.is_finite() {
+        scale.clamp(MIN_TEXT_SCALE, MAX_TEXT_SCALE)
+    } else {
+        1.0
+    }
+}
+
+// file in the app's config directory => platform config directory
+pub fn config_file(name: &str) -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("iced_tutorial").join(name))
+}
+
+// write a file in the config directory, creating the directory on first use
+pub fn write_config_file(name: &str, contents: &str) -> io::Result<()> {
+    let path = config_file(name)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_order_survives_a_round_trip() {
+        let mut settings = AppSettings::default();
+        settings.move_field(FieldKind::Email, -1);
+        settings.move_field(FieldKind::Phone, -1);
+        assert_eq!(
+            settings.field_order,
+            [
+                FieldKind::Email,
+                FieldKind::Username,
+                FieldKind::Password,
+                FieldKind::Phone,
+                FieldKind::ConfirmPassword,
+            ]
+        );
+        assert_eq!(AppSettings::parse(&settings.serialize()), settings);
+    }
+
+    #[test]
+    fn moving_past_either_end_does_nothing() {
+        let mut settings = AppSettings::default();
+        settings.move_field(FieldKind::Username, -1);
+        settings.move_field(FieldKind::Phone, 1);
+        assert_eq!(settings.field_order, FieldKind::ALL);
+    }
+
+    #[test]
+    fn unknown_and_missing_order_keys_are_repaired() {
+        let settings = AppSettings::parse("field_order=password,bogus,password\n");
+        assert_eq!(settings.field_order[0], FieldKind::Password);
+        assert_eq!(settings.field_order.len(), FieldKind::ALL.len());
+    }
+
+    #[test]
+    fn initial_focus_picks_the_email_field_when_shown() {
+        let register = [FieldKind::Username, FieldKind::Email, FieldKind::Password];
+        assert_eq!(InitialFocus::Email.target(&register), Some(FieldKind::Email));
+        // a page without an email field => nothing to focus
+        assert_eq!(InitialFocus::Email.target(&[]), None);
+        assert_eq!(InitialFocus::Nothing.target(&register), None);
+    }
+
+    #[test]
+    fn initial_focus_keys_round_trip() {
+        for focus in InitialFocus::ALL {
+            assert_eq!(InitialFocus::from_key(focus.key()), Some(focus));
+        }
+        assert_eq!(InitialFocus::from_key("username"), None);
+    }
+}
@@ -0,0 +1,190 @@
+// keyboard shortcut registry => drives both the key handler and the help overlay
+use iced::keyboard::{key::Named, Key, Modifiers};
+
+use crate::Page;
+
+// what a shortcut does => mapped to a message by the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Submit,        // primary action of the page (when not typing in a field)
+    FocusEmail,    // focus the email field and select its contents
+    ToggleHelp,    // show or hide the shortcut overlay
+    ToggleErrors,  // show or hide the recent errors overlay
+    Dismiss,       // close the topmost overlay
+    FocusNext,     // move focus to the next field, wrapping to the first
+    FocusPrevious, // move focus to the previous field, wrapping to the last
+}
+
+// key that triggers a shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    Named(Named),
+    Character(&'static str),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Shortcut {
+    pub trigger: Trigger,
+    pub command: bool,      // needs Ctrl (Cmd on macOS)
+    pub shift: bool,        // needs Shift
+    pub in_inputs: bool,    // also fires while a text input has focus
+    pub pages: &'static [Page], // pages the shortcut works on, empty = every page
+    pub label: &'static str, // keys as shown in the help overlay
+    pub description: &'static str,
+    pub action: Action,
+}
+
+impl Shortcut {
+    pub fn matches(&self, key: &Key, modifiers: Modifiers) -> bool {
+        let key_matches = match (self.trigger, key.as_ref()) {
+            (Trigger::Named(named), Key::Named(pressed)) => named == pressed,
+            (Trigger::Character(c), Key::Character(pressed)) => c.eq_ignore_ascii_case(pressed),
+            _ => false,
+        };
+        key_matches && self.command == modifiers.command() && self.shift == modifiers.shift()
+    }
+}
+
+// pages with form fields to move through
+const FORM_PAGES: &[Page] = &[Page::Login, Page::Register];
+
+// pages with a main action for Enter => home has none
+const ACTION_PAGES: &[Page] = &[Page::Login, Page::Register, Page::Settings, Page::Error];
+
+pub const SHORTCUTS: &[Shortcut] = &[
+    Shortcut {
+        trigger: Trigger::Named(Named::Tab),
+        command: false,
+        shift: false,
+        in_inputs: true,
+        pages: FORM_PAGES,
+        label: "Tab",
+        description: "Focus the next field",
+        action: Action::FocusNext,
+    },
+    Shortcut {
+        trigger: Trigger::Named(Named::Tab),
+        command: false,
+        shift: true,
+        in_inputs: true,
+        pages: FORM_PAGES,
+        label: "Shift+Tab",
+        description: "Focus the previous field",
+        action: Action::FocusPrevious,
+    },
+    Shortcut {
+        trigger: Trigger::Named(Named::Enter),
+        command: false,
+        shift: false,
+        in_inputs: false,
+        pages: ACTION_PAGES,
+        label: "Enter",
+        description: "Next field, or the page's main action when enabled in settings",
+        action: Action::Submit,
+    },
+    Shortcut {
+        trigger: Trigger::Character("l"),
+        command: true,
+        shift: false,
+        in_inputs: true,
+        pages: &[Page::Login],
+        label: "Ctrl+L",
+        description: "Focus and select the email field",
+        action: Action::FocusEmail,
+    },
+    Shortcut {
+        trigger: Trigger::Named(Named::F1),
+        command: false,
+        shift: false,
+        in_inputs: true,
+        pages: &[],
+        label: "F1",
+        description: "Show or hide this help",
+        action: Action::ToggleHelp,
+    },
+    Shortcut {
+        trigger: Trigger::Named(Named::F2),
+        command: false,
+        shift: false,
+        in_inputs: true,
+        pages: &[],
+        label: "F2",
+        description: "Show or hide recent errors",
+        action: Action::ToggleErrors,
+    },
+    Shortcut {
+        trigger: Trigger::Named(Named::Escape),
+        command: false,
+        shift: false,
+        in_inputs: true,
+        pages: &[],
+        label: "Esc",
+        description: "Close the topmost dialog, overlay or notice",
+        action: Action::Dismiss,
+    },
+];
+
+// shortcuts active on a page => what the key handler and the help overlay both go by
+pub fn shortcuts_for(page: &Page) -> impl Iterator<Item = &'static Shortcut> + '_ {
+    SHORTCUTS
+        .iter()
+        .filter(move |shortcut| shortcut.pages.is_empty() || shortcut.pages.contains(page))
+}
+
+// the shortcut a key press triggers => `captured` when a widget (a text input) already handled it
+pub fn find(page: &Page, key: &Key, modifiers: Modifiers, captured: bool) -> Option<&'static Shortcut> {
+    shortcuts_for(page).find(|shortcut| shortcut.matches(key, modifiers) && (shortcut.in_inputs || !captured))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action(page: &Page, key: Key, modifiers: Modifiers, captured: bool) -> Option<Action> {
+        find(page, &key, modifiers, captured).map(|shortcut| shortcut.action)
+    }
+
+    fn listed(page: &Page, action: Action) -> bool {
+        shortcuts_for(page).any(|shortcut| shortcut.action == action)
+    }
+
+    #[test]
+    fn ctrl_l_only_on_login() {
+        let ctrl_l = || Key::Character("l".into());
+        assert_eq!(action(&Page::Login, ctrl_l(), Modifiers::COMMAND, true), Some(Action::FocusEmail));
+        assert_eq!(action(&Page::Register, ctrl_l(), Modifiers::COMMAND, false), None);
+        assert!(listed(&Page::Login, Action::FocusEmail));
+        assert!(!listed(&Page::Settings, Action::FocusEmail));
+    }
+
+    #[test]
+    fn tab_only_on_form_pages() {
+        let tab = || Key::Named(Named::Tab);
+        assert_eq!(action(&Page::Register, tab(), Modifiers::empty(), true), Some(Action::FocusNext));
+        assert_eq!(action(&Page::Login, tab(), Modifiers::SHIFT, true), Some(Action::FocusPrevious));
+        for page in &[Page::Home, Page::Settings, Page::Error] {
+            assert_eq!(action(page, tab(), Modifiers::empty(), false), None);
+            assert!(!listed(page, Action::FocusNext));
+        }
+    }
+
+    #[test]
+    fn enter_in_a_text_input_is_left_to_it() {
+        let enter = || Key::Named(Named::Enter);
+        assert_eq!(action(&Page::Login, enter(), Modifiers::empty(), true), None);
+        assert_eq!(action(&Page::Login, enter(), Modifiers::empty(), false), Some(Action::Submit));
+        // home has no main action
+        assert_eq!(action(&Page::Home, enter(), Modifiers::empty(), false), None);
+    }
+
+    #[test]
+    fn global_shortcuts_work_everywhere() {
+        for page in &[Page::Login, Page::Register, Page::Home, Page::Settings, Page::Error] {
+            assert!(listed(page, Action::ToggleHelp));
+            assert_eq!(
+                action(page, Key::Named(Named::Escape), Modifiers::empty(), true),
+                Some(Action::Dismiss)
+            );
+        }
+    }
+}
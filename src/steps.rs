@@ -0,0 +1,37 @@
+// state of each step in a multi-step flow => drives the stepper colors
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepState {
+    Completed,
+    Active,
+    Upcoming,
+}
+
+// steps before `current` are done, `current` is running, the rest are still ahead
+pub fn step_state(index: usize, current: usize) -> StepState {
+    match index.cmp(&current) {
+        std::cmp::Ordering::Less => StepState::Completed,
+        std::cmp::Ordering::Equal => StepState::Active,
+        std::cmp::Ordering::Greater => StepState::Upcoming,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_around_the_current_one() {
+        let states: Vec<StepState> = (0..4).map(|index| step_state(index, 2)).collect();
+        assert_eq!(
+            states,
+            [StepState::Completed, StepState::Completed, StepState::Active, StepState::Upcoming]
+        );
+    }
+
+    #[test]
+    fn first_step_has_nothing_completed() {
+        assert_eq!(step_state(0, 0), StepState::Active);
+        assert_eq!(step_state(1, 0), StepState::Upcoming);
+    }
+}
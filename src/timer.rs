@@ -0,0 +1,35 @@
+// timers without an async runtime => helper threads do the waiting
+use std::thread;
+use std::time::{Duration, Instant};
+
+use iced::futures::channel::oneshot;
+use iced::Subscription;
+
+// async sleep => a helper thread completes a oneshot
+pub async fn sleep(duration: Duration) {
+    let (sender, receiver) = oneshot::channel();
+    thread::spawn(move || {
+        thread::sleep(duration);
+        let _ = sender.send(());
+    });
+    let _ = receiver.await;
+}
+
+// emits the current instant every `duration`
+pub fn every(duration: Duration) -> Subscription<Instant> {
+    iced::subscription::unfold(("timer::every", duration), duration, |duration| async move {
+        sleep(duration).await;
+        (Instant::now(), duration)
+    })
+}
+
+// emits `key` once, `duration` after it starts => a new key restarts it, which debounces
+pub fn after(key: u64, duration: Duration) -> Subscription<u64> {
+    iced::subscription::unfold(("timer::after", key, duration), false, move |fired| async move {
+        if fired {
+            iced::futures::future::pending::<()>().await;
+        }
+        sleep(duration).await;
+        (key, true)
+    })
+}
@@ -0,0 +1,91 @@
+// window position and size remembered between runs => `key=value` lines like the settings
+use std::fs;
+use std::io;
+
+use iced::window::Position;
+use iced::{Point, Size};
+
+use crate::settings;
+
+const WINDOW_FILE: &str = "window.conf";
+
+// a restored window keeps at least this much of itself on screen
+const VISIBLE_MARGIN: f32 = 100.0;
+
+// positions beyond this are treated as a monitor that is gone
+const MAX_COORDINATE: f32 = 16_384.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowState {
+    pub position: Option<Point>, // unknown on first launch and on wayland, which never reports it
+    pub size: Size,
+}
+
+impl Default for WindowState {
+    fn default() -> Self {
+        Self {
+            position: None,
+            size: iced::window::Settings::default().size,
+        }
+    }
+}
+
+impl WindowState {
+    // missing or unreadable file => first launch defaults
+    pub fn load() -> Self {
+        match settings::config_file(WINDOW_FILE).map(fs::read_to_string) {
+            Some(Ok(contents)) => Self::parse(&contents),
+            _ => Self::default(),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        settings::write_config_file(WINDOW_FILE, &self.serialize())
+    }
+
+    pub fn parse(contents: &str) -> Self {
+        let mut state = Self::default();
+        let (mut x, mut y) = (None, None);
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let Ok(value) = value.trim().parse::<f32>() else {
+                continue;
+            };
+            match key.trim() {
+                "x" => x = Some(value),
+                "y" => y = Some(value),
+                "width" if value >= 1.0 => state.size.width = value,
+                "height" if value >= 1.0 => state.size.height = value,
+                _ => {}
+            }
+        }
+        state.position = x.zip(y).map(|(x, y)| Point::new(x, y));
+        state
+    }
+
+    pub fn serialize(&self) -> String {
+        let position = self
+            .position
+            .map_or_else(String::new, |point| format!("x={}\ny={}\n", point.x, point.y));
+        format!("{position}width={}\nheight={}\n", self.size.width, self.size.height)
+    }
+
+    // where to open the window => centered unless the saved spot is still plausible
+    // monitors can't be queried before the window exists, so this only catches positions
+    // that leave the window unreachable (title bar above the screen, almost fully off to the side)
+    pub fn position(&self) -> Position {
+        match self.position {
+            Some(point)
+                if point.y >= 0.0
+                    && point.x >= VISIBLE_MARGIN - self.size.width
+                    && point.x <= MAX_COORDINATE
+                    && point.y <= MAX_COORDINATE =>
+            {
+                Position::Specific(point)
+            }
+            _ => Position::Centered,
+        }
+    }
+}